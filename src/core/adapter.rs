@@ -57,59 +57,105 @@ where
 }
 
 #[derive(Debug)]
-pub(super) struct MatchAdapter<M, Actual>
-where
-    M: Match<Actual>,
-{
-    inner: M,
+pub(super) struct DynMatchAdapter<M, Actual, Fmt> {
+    matcher: M,
+    format: Fmt,
+    negated: bool,
     marker: PhantomData<Actual>,
 }
 
-impl<M, Actual> MatchAdapter<M, Actual>
+impl<M, Actual, Fmt> DynMatchAdapter<M, Actual, Fmt> {
+    pub fn new(matcher: M, format: Fmt, negated: bool) -> Self {
+        Self {
+            matcher,
+            format,
+            negated,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M, Actual, Fmt> DynMatchAdapter<M, Actual, Fmt>
 where
     M: Match<Actual>,
+    Fmt: MatcherFormat<Pos = M::Fail, Neg = M::Fail>,
 {
-    pub fn new(inner: M) -> Self {
-        Self {
-            inner,
-            marker: PhantomData,
+    // Unlike `match_pos` and `match_neg`, this formats the description of the matcher when it
+    // succeeds, if it has one.
+    fn describe(
+        mut self,
+        actual: Actual,
+        pos: bool,
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        let format = |output| {
+            if pos {
+                FormattedFailure::new(MatchFailure::Pos(output), self.format)
+            } else {
+                FormattedFailure::new(MatchFailure::Neg(output), self.format)
+            }
+        };
+
+        if (self.matcher.matches(&actual)? != self.negated) == pos {
+            Ok(MatchOutcome::Success(
+                self.matcher.describe(actual).map(format).transpose()?,
+            ))
+        } else {
+            Ok(MatchOutcome::Fail(format(self.matcher.fail(actual))?))
         }
     }
 }
 
-impl<M, Actual> TransformMatch for MatchAdapter<M, Actual>
+impl<M, Actual, Fmt> DynTransformMatch for DynMatchAdapter<M, Actual, Fmt>
 where
     M: Match<Actual>,
+    Fmt: MatcherFormat<Pos = M::Fail, Neg = M::Fail>,
 {
     type In = Actual;
 
     type PosOut = Actual;
     type NegOut = Actual;
 
-    type PosFail = M::Fail;
-    type NegFail = M::Fail;
-
     fn match_pos(
-        mut self,
+        mut self: Box<Self>,
         actual: Self::In,
-    ) -> crate::Result<MatchOutcome<Self::PosOut, Self::PosFail>> {
-        match self.inner.matches(&actual) {
-            Ok(true) => Ok(MatchOutcome::Success(actual)),
-            Ok(false) => Ok(MatchOutcome::Fail(self.inner.fail(actual))),
-            Err(error) => Err(error),
+    ) -> crate::Result<MatchOutcome<Self::PosOut, FormattedFailure>> {
+        if self.matcher.matches(&actual)? != self.negated {
+            Ok(MatchOutcome::Success(actual))
+        } else {
+            Ok(MatchOutcome::Fail(FormattedFailure::new(
+                MatchFailure::Pos(self.matcher.fail(actual)),
+                self.format,
+            )?))
         }
     }
 
     fn match_neg(
-        mut self,
+        mut self: Box<Self>,
         actual: Self::In,
-    ) -> crate::Result<MatchOutcome<Self::NegOut, Self::NegFail>> {
-        match self.inner.matches(&actual) {
-            Ok(true) => Ok(MatchOutcome::Fail(self.inner.fail(actual))),
-            Ok(false) => Ok(MatchOutcome::Success(actual)),
-            Err(error) => Err(error),
+    ) -> crate::Result<MatchOutcome<Self::NegOut, FormattedFailure>> {
+        if self.matcher.matches(&actual)? == self.negated {
+            Ok(MatchOutcome::Success(actual))
+        } else {
+            Ok(MatchOutcome::Fail(FormattedFailure::new(
+                MatchFailure::Neg(self.matcher.fail(actual)),
+                self.format,
+            )?))
         }
     }
+
+    fn describe_pos(
+        self: Box<Self>,
+        actual: Self::In,
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        (*self).describe(actual, true)
+    }
+
+    fn describe_neg(
+        self: Box<Self>,
+        actual: Self::In,
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        (*self).describe(actual, false)
+    }
 }
 
 #[derive(Debug)]
@@ -3,7 +3,9 @@ use std::convert::TryInto;
 use crate::matchers::map::IterMap;
 
 use super::{
-    AssertionFailure, AssertionFormat, DynTransformMatch, FormattedOutput, MatchError, MatchOutcome,
+    catch_panic, current_context, AssertionContext, AssertionFailure, AssertionFormat,
+    DefaultAssertionFormat, DynTransformMatch, FileLocation, FormattedOutput, MatchError,
+    MatchOutcome,
 };
 
 /// An assertion, the starting point in a chain of matchers.
@@ -11,7 +13,7 @@ use super::{
 /// This is the value returned by [`expect!`]. You can use the [`to`] and [`to_not`] methods to use
 /// matchers, chaining the output of each into the input of the next.
 ///
-/// [`expect!`]: crate::expect
+/// [`expect!`]: crate::expect!
 /// [`to`]: crate::core::Assertion::to
 /// [`to_not`]: crate::core::Assertion::to_not
 #[derive(Debug)]
@@ -24,13 +26,21 @@ where
     formatter: AssertFmt,
 }
 
+#[track_caller]
 fn fail<Ctx, AssertFmt>(ctx: Ctx, error: MatchError, format: AssertFmt) -> !
 where
     AssertFmt: AssertionFormat<Context = Ctx>,
 {
-    FormattedOutput::new(AssertionFailure { ctx, error }, format)
-        .expect("failed to format matcher output")
-        .fail();
+    FormattedOutput::new(
+        AssertionFailure {
+            ctx,
+            context: current_context(),
+            error,
+        },
+        format,
+    )
+    .expect("failed to format matcher output")
+    .fail();
 }
 
 impl<In, AssertFmt> Assertion<In, AssertFmt>
@@ -48,7 +58,7 @@ where
     /// However, if you want to use a custom [`AssertionFormat`], then creating an assertion this
     /// way allows you to do it.
     ///
-    /// [`expect!`]: crate::expect
+    /// [`expect!`]: crate::expect!
     pub fn new(value: In, ctx: AssertFmt::Context) -> Self {
         Self {
             value,
@@ -62,12 +72,31 @@ impl<In, AssertFmt> Assertion<In, AssertFmt>
 where
     AssertFmt: AssertionFormat,
 {
+    /// Create a new [`Assertion`] which uses the given `format`.
+    ///
+    /// This is the same as [`new`], but accepts a formatter value instead of using its [`Default`]
+    /// implementation. The [`expect_with!`] macro calls this method.
+    ///
+    /// [`new`]: crate::core::Assertion::new
+    /// [`expect_with!`]: crate::expect_with
+    pub fn with_format(value: In, ctx: AssertFmt::Context, format: AssertFmt) -> Self {
+        Self {
+            value,
+            ctx,
+            formatter: format,
+        }
+    }
+
     /// Make an assertion with the given `matcher`.
+    ///
+    /// If the matcher or a closure passed to it panics, the panic is caught and the assertion fails
+    /// with an error reporting the panic message.
+    #[track_caller]
     pub fn to<Out>(
         self,
         matcher: impl DynTransformMatch<In = In, PosOut = Out>,
     ) -> Assertion<Out, AssertFmt> {
-        match Box::new(matcher).match_pos(self.value) {
+        match catch_panic(|| Box::new(matcher).match_pos(self.value)).and_then(|result| result) {
             Ok(MatchOutcome::Success(out)) => Assertion {
                 value: out,
                 formatter: self.formatter,
@@ -96,11 +125,12 @@ where
     ///
     /// [`to`]: crate::core::Assertion::to
     /// [`not`]: crate::not
+    #[track_caller]
     pub fn to_not<Out>(
         self,
         matcher: impl DynTransformMatch<In = In, NegOut = Out>,
     ) -> Assertion<Out, AssertFmt> {
-        match Box::new(matcher).match_neg(self.value) {
+        match catch_panic(|| Box::new(matcher).match_neg(self.value)).and_then(|result| result) {
             Ok(MatchOutcome::Success(out)) => Assertion {
                 value: out,
                 formatter: self.formatter,
@@ -133,11 +163,15 @@ where
     /// ```
     ///
     /// [`map`]: crate::map
+    #[track_caller]
     pub fn map<Out>(self, func: impl FnOnce(In) -> Out) -> Assertion<Out, AssertFmt> {
-        Assertion {
-            value: func(self.value),
-            formatter: self.formatter,
-            ctx: self.ctx,
+        match catch_panic(|| func(self.value)) {
+            Ok(out) => Assertion {
+                value: out,
+                formatter: self.formatter,
+                ctx: self.ctx,
+            },
+            Err(error) => fail(self.ctx, MatchError::Err(error), self.formatter),
         }
     }
 
@@ -156,11 +190,12 @@ where
     /// ```
     ///
     /// [`try_map`]: crate::map
+    #[track_caller]
     pub fn try_map<Out>(
         self,
         func: impl FnOnce(In) -> crate::Result<Out>,
     ) -> Assertion<Out, AssertFmt> {
-        match func(self.value) {
+        match catch_panic(|| func(self.value)).and_then(|result| result) {
             Ok(out) => Assertion {
                 value: out,
                 formatter: self.formatter,
@@ -211,6 +246,7 @@ where
     /// ```
     ///
     /// [`try_into`]: crate::try_into
+    #[track_caller]
     pub fn try_into<Out>(self) -> Assertion<Out, AssertFmt>
     where
         Out: TryFrom<In>,
@@ -241,7 +277,7 @@ where
     ///     .into_inner();
     /// ```
     ///
-    /// [`expect!`]: crate::expect
+    /// [`expect!`]: crate::expect!
     /// [`be_ok`]: crate::be_ok
     /// [`be_some`]: crate::be_some
     pub fn into_inner(self) -> In {
@@ -334,15 +370,15 @@ where
     /// ```
     ///
     /// [`iter_try_map`]: crate::iter_try_map
+    #[track_caller]
     pub fn iter_try_map<'a, Out>(
         self,
         func: impl Fn(In::Item) -> crate::Result<Out> + 'a,
     ) -> Assertion<Vec<Out>, AssertFmt> {
-        let mapped_values = self
-            .value
-            .into_iter()
-            .map(func)
-            .collect::<Result<Vec<_>, _>>();
+        let values = self.value;
+        let mapped_values =
+            catch_panic(|| values.into_iter().map(func).collect::<Result<Vec<_>, _>>())
+                .and_then(|result| result);
 
         Assertion {
             value: match mapped_values {
@@ -355,14 +391,49 @@ where
     }
 }
 
+/// Make an assertion, using the location of the caller.
+///
+/// This is like [`expect!`], except that it's a function. It gets the location of the assertion
+/// from [`Location::caller`], so when you call it from a helper function marked with
+/// `#[track_caller]`, failures point to the line which called the helper instead of the line
+/// inside it. Because this is a function, it can't capture the expression that was passed to it.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{be_ge, expect};
+///
+/// struct User {
+///     age: u32,
+/// }
+///
+/// #[track_caller]
+/// fn assert_adult(user: &User) {
+///     expect(user.age).to(be_ge(18));
+/// }
+///
+/// assert_adult(&User { age: 30 });
+/// ```
+///
+/// [`expect!`]: crate::expect!
+/// [`Location::caller`]: std::panic::Location::caller
+#[track_caller]
+pub fn expect<In>(value: In) -> Assertion<In, DefaultAssertionFormat> {
+    let ctx = AssertionContext {
+        location: Some(FileLocation::caller()),
+        ..Default::default()
+    };
+
+    Assertion::with_format(value, ctx, DefaultAssertionFormat)
+}
+
 /// Make an assertion.
 ///
 /// This macro accepts an expression and returns an [`Assertion`], which allows you to make
 /// assertions on that value.
 ///
-/// Under the hood, this macro calls [`Assertion::new`] using [`DefaultAssertionFormat`]. If you
-/// want to use a custom [`AssertionFormat`] instead, you can call that method directly or write
-/// your own macro that calls it.
+/// Under the hood, this macro calls [`Assertion::with_format`] using [`DefaultAssertionFormat`].
+/// If you want to use a different [`AssertionFormat`] instead, use [`expect_with!`].
 ///
 /// # Examples
 ///
@@ -372,22 +443,97 @@ where
 /// expect!("disco").to(equal("disco"));
 /// ```
 ///
-/// [`Assertion::new`]: crate::core::Assertion::new
+/// [`Assertion::with_format`]: crate::core::Assertion::with_format
 /// [`AssertionFormat`]: crate::core::AssertionFormat
 /// [`DefaultAssertionFormat`]: crate::core::DefaultAssertionFormat
+/// [`expect_with!`]: crate::expect_with
 #[macro_export]
 macro_rules! expect {
     ($actual:expr) => {
-        $crate::core::Assertion::<_, $crate::core::DefaultAssertionFormat>::new($actual, {
-            let mut ctx = <$crate::core::AssertionContext as ::std::default::Default>::default();
-            ctx.expr =
-                ::std::option::Option::Some(::std::string::String::from(stringify!($actual)));
-            ctx.location = ::std::option::Option::Some($crate::core::FileLocation {
-                file: ::std::string::String::from(file!()),
-                line: line!(),
-                column: column!(),
-            });
-            ctx
-        })
+        $crate::expect_with!($crate::core::DefaultAssertionFormat, $actual)
     };
 }
+
+/// Make an assertion which uses the given [`AssertionFormat`].
+///
+/// This is the same as [`expect!`], except it accepts the formatter to use for failures as its
+/// first argument. The formatter must accept an [`AssertionContext`], like
+/// [`CompactAssertionFormat`].
+///
+/// To use a different formatter for all your assertions, you can define your own macro which calls
+/// this one.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::core::CompactAssertionFormat;
+/// use xpct::{expect_with, equal};
+///
+/// expect_with!(CompactAssertionFormat, "disco").to(equal("disco"));
+/// ```
+///
+/// Define a macro to use it everywhere:
+///
+/// ```
+/// use xpct::equal;
+///
+/// macro_rules! check {
+///     ($actual:expr) => {
+///         xpct::expect_with!(xpct::core::CompactAssertionFormat, $actual)
+///     };
+/// }
+///
+/// check!("disco").to(equal("disco"));
+/// ```
+///
+/// [`expect!`]: crate::expect!
+/// [`AssertionFormat`]: crate::core::AssertionFormat
+/// [`AssertionContext`]: crate::core::AssertionContext
+/// [`CompactAssertionFormat`]: crate::core::CompactAssertionFormat
+#[macro_export]
+macro_rules! expect_with {
+    ($format:expr, $actual:expr) => {
+        $crate::core::Assertion::with_format(
+            $actual,
+            {
+                let mut ctx =
+                    <$crate::core::AssertionContext as ::std::default::Default>::default();
+                ctx.expr =
+                    ::std::option::Option::Some(::std::string::String::from(stringify!($actual)));
+                ctx.location = ::std::option::Option::Some($crate::core::FileLocation {
+                    file: ::std::string::String::from(file!()),
+                    line: line!(),
+                    column: column!(),
+                });
+                ctx
+            },
+            $format,
+        )
+    };
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use std::panic;
+
+    use super::expect;
+    use crate::be_gt;
+
+    #[track_caller]
+    fn assert_positive(value: i32) {
+        expect(value).to(be_gt(0));
+    }
+
+    #[test]
+    fn reports_location_of_track_caller_helper_caller() {
+        let line = line!() + 1;
+        let result = panic::catch_unwind(|| assert_positive(-1));
+
+        let payload = result.unwrap_err();
+        let message = payload
+            .downcast_ref::<String>()
+            .expect("the panic message should be a string");
+
+        assert!(message.contains(&format!("{}:{}:", file!(), line)));
+    }
+}
@@ -1,3 +1,10 @@
+use std::cell::RefCell;
+use std::panic::Location;
+
+thread_local! {
+    static CONTEXT_STACK: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
 /// A location in a Rust source file.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FileLocation {
@@ -11,6 +18,25 @@ pub struct FileLocation {
     pub column: u32,
 }
 
+impl FileLocation {
+    /// The location of the caller of this function.
+    ///
+    /// This uses [`Location::caller`], so if it's called from a function marked with
+    /// `#[track_caller]`, this returns the location of the caller of that function.
+    ///
+    /// [`Location::caller`]: std::panic::Location::caller
+    #[track_caller]
+    pub fn caller() -> Self {
+        let location = Location::caller();
+
+        Self {
+            file: String::from(location.file()),
+            line: location.line(),
+            column: location.column(),
+        }
+    }
+}
+
 /// The context value associated with [`DefaultAssertionFormat`].
 ///
 /// [`DefaultAssertionFormat`]: crate::core::DefaultAssertionFormat
@@ -23,3 +49,141 @@ pub struct AssertionContext {
     /// The expression that the assertion was made against.
     pub expr: Option<String>,
 }
+
+/// Run `f` in a context scope with the given description.
+///
+/// If an assertion made with [`expect!`] or [`expect`] fails while `f` is running, the failure
+/// output includes the descriptions of all the context scopes it's nested inside of, outermost
+/// first. This is useful for telling which case failed when a helper function makes assertions on
+/// many different values.
+///
+/// Context scopes are tracked per thread, so assertions made in threads spawned by `f` don't
+/// inherit them. To push a context scope without a closure, use [`ContextGuard`].
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{be_gt, expect};
+///
+/// struct Order {
+///     id: u32,
+///     totals: Vec<u32>,
+/// }
+///
+/// let order = Order {
+///     id: 42,
+///     totals: vec![10, 20],
+/// };
+///
+/// xpct::context(format!("validating order {}", order.id), || {
+///     for (i, total) in order.totals.iter().enumerate() {
+///         xpct::context(format!("checking line item {}", i), || {
+///             expect!(total).to(be_gt(&0));
+///         });
+///     }
+/// });
+/// ```
+///
+/// [`expect!`]: crate::expect!
+/// [`expect`]: crate::expect()
+pub fn context<T>(description: impl Into<String>, f: impl FnOnce() -> T) -> T {
+    let _guard = ContextGuard::new(description);
+    f()
+}
+
+/// A guard which keeps a context scope open until it's dropped.
+///
+/// This is the same as [`context`], but instead of running a closure in the scope, the scope lasts
+/// until the guard goes out of scope.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::core::ContextGuard;
+/// use xpct::{equal, expect};
+///
+/// fn check_greeting(name: &str, greeting: &str) {
+///     let _guard = ContextGuard::new(format!("greeting {}", name));
+///
+///     expect!(greeting).to(equal(format!("Hello, {}!", name).as_str()));
+/// }
+///
+/// check_greeting("Kim", "Hello, Kim!");
+/// ```
+///
+/// [`context`]: crate::context
+#[derive(Debug)]
+#[must_use = "the context scope is closed when the guard is dropped"]
+pub struct ContextGuard {
+    // Guards must be dropped on the thread that created them, in the reverse order.
+    _marker: std::marker::PhantomData<*const ()>,
+}
+
+impl ContextGuard {
+    /// Open a context scope with the given description.
+    pub fn new(description: impl Into<String>) -> Self {
+        let description = description.into();
+        CONTEXT_STACK.with(|stack| stack.borrow_mut().push(description));
+
+        Self {
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CONTEXT_STACK.with(|stack| stack.borrow_mut().pop());
+    }
+}
+
+/// Return the descriptions of the [`context`] scopes open on this thread, outermost first.
+///
+/// [`context`]: crate::context
+pub fn current_context() -> Vec<String> {
+    CONTEXT_STACK.with(|stack| stack.borrow().clone())
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use std::panic;
+
+    use super::{context, current_context, ContextGuard};
+    use crate::{be_true, expect};
+
+    #[test]
+    fn nested_scopes_are_outermost_first() {
+        context("outer", || {
+            let _guard = ContextGuard::new("inner");
+            assert_eq!(current_context(), vec!["outer", "inner"]);
+        });
+
+        assert!(current_context().is_empty());
+    }
+
+    #[test]
+    fn scopes_are_closed_on_panic() {
+        let result = panic::catch_unwind(|| context("scope", || panic!("failed")));
+
+        assert!(result.is_err());
+        assert!(current_context().is_empty());
+    }
+
+    #[test]
+    fn failures_show_scopes_open_when_they_fail() {
+        let assertion = expect!(false);
+
+        let result = panic::catch_unwind(|| {
+            context("checking flag", || {
+                assertion.to(be_true());
+            })
+        });
+
+        let message = result.unwrap_err();
+        let message = message
+            .downcast_ref::<String>()
+            .expect("panic payload should be a string");
+
+        assert!(message.contains("checking flag"));
+    }
+}
@@ -1,13 +1,103 @@
-use super::{Format, FormattedOutput, Formatter};
-use crate::core::{style, AssertionContext, AssertionFailure, MatchError};
+use std::backtrace::BacktraceStatus;
+
+use super::catalog::message;
+use super::config::with_config;
+use super::snippet::SourceSnippet;
+use super::strings::LineJoiner;
+use super::{terminal, Format, FormattedOutput, Formatter};
+use crate::core::{style, AssertionContext, AssertionFailure, FileLocation, MatchError};
+
+/// Write the location of an assertion, as a hyperlink to the file if the terminal supports it.
+fn write_location(f: &mut Formatter, location: &FileLocation, column: bool) {
+    let text = if column {
+        format!("{}:{}:{}", location.file, location.line, location.column)
+    } else {
+        format!("{}:{}", location.file, location.line)
+    };
+
+    match terminal::file_url(&location.file) {
+        Some(url) => f.write_link(text, url),
+        None => f.write_str(text),
+    }
+}
+
+/// Write an error returned by a matcher, along with its chain of sources and its backtrace.
+fn write_error(f: &mut Formatter, error: &crate::Error) -> crate::Result<()> {
+    f.set_style(style::bad());
+    f.write_str(style::error_header(error.kind()));
+    f.reset_style();
+    f.write_char('\n');
+
+    let mut chain = error.chain();
+
+    if let Some(outer) = chain.next() {
+        f.indented(style::indent(1), |f| {
+            f.write_str(outer.to_string());
+            Ok(())
+        })?;
+    }
+
+    let sources = chain.collect::<Vec<_>>();
+
+    if !sources.is_empty() {
+        f.write_char('\n');
+        f.set_style(style::info());
+        f.write_str(message("error.caused-by"));
+        f.reset_style();
+
+        for (i, source) in sources.into_iter().enumerate() {
+            f.write_char('\n');
+            f.indented(style::indent(1), |f| {
+                f.write_str(format!("{}: {}", i, source));
+                Ok(())
+            })?;
+        }
+    }
+
+    let backtrace = error.backtrace();
+
+    if backtrace.status() == BacktraceStatus::Captured {
+        f.write_char('\n');
+        f.set_style(style::info());
+        f.write_str(message("error.backtrace"));
+        f.reset_style();
+        f.write_char('\n');
+
+        f.indented(style::indent(1), |f| {
+            f.write_str(backtrace.to_string().trim_end());
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
 
 /// The provided implementation of [`AssertionFormat`].
 ///
 /// This [`AssertionFormat`] implementation prints the expression that was passed to [`expect!`]
-/// along with the file name, line number, and column number.
+/// along with the file name, line number, and column number. When the terminal supports it, the
+/// location is a hyperlink to the file.
 ///
+/// If [`Config::source_snippet`] is enabled, this also prints the lines of source code around the
+/// assertion, with a caret under the column where it was made. This is skipped if the source file
+/// can't be read.
+///
+/// If the assertion was made inside of any [`context`] scopes, their descriptions are printed
+/// above the failure, with each one nested inside the one before it.
+///
+/// If the matcher returned an [`Error`] instead of failing, this prints a header with its
+/// [`ErrorKind`], its chain of sources, and its backtrace if one was captured.
+///
+/// If [`Config::compact`] is enabled, this prints each failure on a single line like
+/// [`CompactAssertionFormat`] instead.
+///
+/// [`context`]: crate::context
 /// [`expect!`]: crate::expect!
+/// [`Error`]: crate::Error
+/// [`ErrorKind`]: crate::ErrorKind
 /// [`Assertionformat`]: crate::core::AssertionFormat
+/// [`Config::compact`]: crate::core::Config::compact
+/// [`Config::source_snippet`]: crate::core::Config::source_snippet
 #[derive(Debug, Default)]
 pub struct DefaultAssertionFormat;
 
@@ -15,18 +105,23 @@ impl Format for DefaultAssertionFormat {
     type Value = AssertionFailure<AssertionContext>;
 
     fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
+        if with_config(|config| config.compact) {
+            return CompactAssertionFormat.fmt(f, value);
+        }
+
         f.write_char('\n');
         f.set_style(style::info());
 
-        match (value.ctx.location, value.ctx.expr) {
-            (Some(location), Some(expr)) => f.write_str(format!(
-                "[{}:{}:{}] = {}\n",
-                location.file, location.line, location.column, expr
-            )),
-            (Some(location), None) => f.write_str(format!(
-                "{}:{}:{}\n",
-                location.file, location.line, location.column
-            )),
+        match (&value.ctx.location, &value.ctx.expr) {
+            (Some(location), Some(expr)) => {
+                f.write_char('[');
+                write_location(f, location, true);
+                f.write_str(format!("] = {}\n", expr));
+            }
+            (Some(location), None) => {
+                write_location(f, location, true);
+                f.write_char('\n');
+            }
             (None, Some(expr)) => {
                 f.write_str(expr);
                 f.write_char('\n');
@@ -36,14 +131,31 @@ impl Format for DefaultAssertionFormat {
 
         f.reset_style();
 
+        let snippet = value.ctx.location.as_ref().and_then(|location| {
+            with_config(|config| config.source_snippet)
+                .and_then(|context| SourceSnippet::read(location, context))
+        });
+
+        if let Some(snippet) = snippet {
+            snippet.fmt(f);
+            f.write_char('\n');
+        }
+
+        // Each context scope is nested inside the one before it, and the failure is nested inside
+        // the innermost one.
+        for (depth, description) in value.context.iter().enumerate() {
+            f.write_str(style::indent(depth as u32 + 1));
+            f.set_style(style::info());
+            f.write_str(description);
+            f.reset_style();
+            f.write_char('\n');
+        }
+
+        let indent = style::indent(value.context.len() as u32 + 1);
+
         match value.error {
-            MatchError::Fail(fail) => {
-                f.write_fmt(FormattedOutput::from(fail).indented(style::indent(1)))
-            }
-            MatchError::Err(error) => f.indented(style::indent(1), |f| {
-                f.write_str(error.to_string());
-                Ok(())
-            })?,
+            MatchError::Fail(fail) => f.write_fmt(FormattedOutput::from(fail).indented(indent)),
+            MatchError::Err(error) => f.indented(indent, |f| write_error(f, &error))?,
         };
 
         f.write_char('\n');
@@ -51,3 +163,78 @@ impl Format for DefaultAssertionFormat {
         Ok(())
     }
 }
+
+/// An [`AssertionFormat`] which prints each failure on a single line.
+///
+/// This prints the file name and line number, the expression that was passed to [`expect!`], the
+/// descriptions of any [`context`] scopes, and the failure message with its line breaks collapsed,
+/// like this:
+///
+/// ```text
+/// src/lib.rs:42: parse_ratio(input): Expected: Ok(1.5) to equal: Ok(2.0)
+/// ```
+///
+/// This is useful when running many table-driven test cases in CI, where one line per failure is
+/// easier to scan and to search. Use it with [`expect_with!`], or enable [`Config::compact`] to
+/// make [`DefaultAssertionFormat`] print failures this way.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::core::CompactAssertionFormat;
+/// use xpct::{equal, expect_with};
+///
+/// expect_with!(CompactAssertionFormat, "disco").to(equal("disco"));
+/// ```
+///
+/// [`expect!`]: crate::expect!
+/// [`expect_with!`]: crate::expect_with!
+/// [`context`]: crate::context
+/// [`AssertionFormat`]: crate::core::AssertionFormat
+/// [`Config::compact`]: crate::core::Config::compact
+#[derive(Debug, Default)]
+pub struct CompactAssertionFormat;
+
+impl Format for CompactAssertionFormat {
+    type Value = AssertionFailure<AssertionContext>;
+
+    fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
+        f.set_style(style::info());
+
+        if let Some(location) = &value.ctx.location {
+            write_location(f, location, false);
+            f.write_str(": ");
+        }
+
+        if let Some(expr) = &value.ctx.expr {
+            let mut joined = String::with_capacity(expr.len());
+            LineJoiner::default().push(&mut joined, expr);
+            f.write_str(joined);
+            f.write_str(": ");
+        }
+
+        for description in &value.context {
+            f.write_str(description);
+            f.write_str(": ");
+        }
+
+        f.reset_style();
+
+        match value.error {
+            MatchError::Fail(fail) => f.write_fmt(FormattedOutput::from(fail).joined()),
+            MatchError::Err(error) => {
+                f.set_style(style::bad());
+                f.write_str(style::error_header(error.kind()));
+                f.reset_style();
+                f.write_char(' ');
+
+                // The alternate form includes the chain of sources, separated by colons.
+                let mut joined = String::new();
+                LineJoiner::default().push(&mut joined, &format!("{:#}", error));
+                f.write_str(joined);
+            }
+        };
+
+        Ok(())
+    }
+}
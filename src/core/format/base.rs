@@ -59,7 +59,7 @@ where
 /// [`Format::Value`] is an [`AssertionFailure`], so you should never need to implement this trait
 /// yourself.
 ///
-/// [`expect!`]: crate::expect
+/// [`expect!`]: crate::expect!
 /// [`Assertion::new`]: crate::core::Assertion::new
 /// [`DefaultAssertionFormat`]: crate::core::DefaultAssertionFormat
 pub trait AssertionFormat: Format<Value = AssertionFailure<Self::Context>> {
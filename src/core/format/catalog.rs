@@ -0,0 +1,557 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::RwLock;
+
+use super::strings::group_digits;
+
+static CATALOG: RwLock<Option<Box<dyn Catalog>>> = RwLock::new(None);
+
+/// A source of the user-facing messages in failure output.
+///
+/// Every message printed by the provided matchers and formatters has a stable ID, like
+/// `equal.pos`, and an English translation in [`EnglishCatalog`]. You can translate the output by
+/// implementing this trait and installing it with [`set_catalog`]. Messages which your catalog
+/// doesn't have fall back to English.
+///
+/// Messages can contain parameters in braces, like `{count}`, which are filled in when the
+/// message is formatted. Messages about a number of things, like `… {count} unchanged lines …`,
+/// are passed that number as `count` so you can choose the right plural form for your language,
+/// and the number is formatted with [`Catalog::format_count`].
+///
+/// The provided matchers look up their messages each time a failure is formatted, so you can
+/// install a catalog at any point before your assertions fail.
+///
+/// This trait is implemented for:
+///
+/// - Closures which accept a message ID and an optional count and return an `Option<String>`.
+/// - [`HashMap<String, String>`] from message IDs to messages. When the count is `1`, the message
+///   with the ID `{id}.one` is used if there is one, like in [`EnglishCatalog`].
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use xpct::core::set_catalog;
+///
+/// let mut catalog = HashMap::new();
+///
+/// catalog.insert(String::from("equal.pos"), String::from("gleich zu sein"));
+/// catalog.insert(String::from("equal.neg"), String::from("nicht gleich zu sein"));
+///
+/// set_catalog(catalog);
+/// ```
+///
+/// Choose plural forms with a closure:
+///
+/// ```
+/// use xpct::core::set_catalog;
+///
+/// set_catalog(|id: &str, count: Option<usize>| {
+///     let message = match (id, count.map(|count| count % 10)) {
+///         ("diff.unchanged-lines", Some(1)) => "… {count} строка без изменений …",
+///         ("diff.unchanged-lines", Some(2..=4)) => "… {count} строки без изменений …",
+///         ("diff.unchanged-lines", _) => "… {count} строк без изменений …",
+///         _ => return None,
+///     };
+///
+///     Some(String::from(message))
+/// });
+/// ```
+pub trait Catalog: Send + Sync {
+    /// Return the message with the given ID, or `None` to fall back to English.
+    ///
+    /// If the message is about a number of things, `count` is that number.
+    fn message(&self, id: &str, count: Option<usize>) -> Option<String>;
+
+    /// Format a number to fill in the `{count}` parameter of a message.
+    ///
+    /// The default implementation formats the number without any separators, like `1500`.
+    fn format_count(&self, count: usize) -> String {
+        count.to_string()
+    }
+}
+
+impl<F> Catalog for F
+where
+    F: Fn(&str, Option<usize>) -> Option<String> + Send + Sync,
+{
+    fn message(&self, id: &str, count: Option<usize>) -> Option<String> {
+        self(id, count)
+    }
+}
+
+impl Catalog for HashMap<String, String> {
+    fn message(&self, id: &str, count: Option<usize>) -> Option<String> {
+        count
+            .filter(|&count| count == 1)
+            .and_then(|_| self.get(&format!("{}.one", id)))
+            .or_else(|| self.get(id))
+            .cloned()
+    }
+}
+
+/// The English messages, which are used by default.
+///
+/// See [`EnglishCatalog::MESSAGES`] for the IDs of all the messages in failure output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EnglishCatalog;
+
+impl EnglishCatalog {
+    /// Every message ID along with its English message.
+    ///
+    /// Messages whose ID ends with `.one` are the singular form of the message without that
+    /// suffix, used when the count is `1`.
+    pub const MESSAGES: &'static [(&'static str, &'static str)] = &[
+        ("header.all-ok", "Expected all of these to succeed:"),
+        (
+            "header.any-ok",
+            "Expected at least one of these to succeed:",
+        ),
+        (
+            "header.all-fields-ok",
+            "Expected all of these fields to succeed:",
+        ),
+        (
+            "header.any-field-ok",
+            "Expected at least one of these fields to succeed:",
+        ),
+        (
+            "header.any-not-ok",
+            "Expected at least one of these to fail.",
+        ),
+        ("label.expected", "Expected:"),
+        ("error.input", "Matcher error (invalid input):"),
+        ("error.io", "Matcher error (I/O):"),
+        ("error.panic", "Matcher error (panic):"),
+        ("error.other", "Matcher error:"),
+        ("error.caused-by", "Caused by:"),
+        ("error.backtrace", "Backtrace:"),
+        ("truncate.more-elements", "… {count} more elements …"),
+        ("truncate.more-elements.one", "… {count} more element …"),
+        ("truncate.more-entries", "… {count} more entries …"),
+        ("truncate.more-entries.one", "… {count} more entry …"),
+        ("truncate.more-fields", "… {count} more fields …"),
+        ("truncate.more-fields.one", "… {count} more field …"),
+        ("truncate.more-characters", "… {count} more characters …"),
+        ("truncate.more-characters.one", "… {count} more character …"),
+        ("diff.pos", "Expected these to be equal:"),
+        ("diff.neg", "Expected these to not be equal:"),
+        ("diff.expected", "expected"),
+        ("diff.actual", "actual"),
+        ("diff.unchanged-lines", "… {count} unchanged lines …"),
+        ("diff.unchanged-lines.one", "… {count} unchanged line …"),
+        ("diff.unchanged-entries", "… {count} unchanged entries …"),
+        ("diff.unchanged-entries.one", "… {count} unchanged entry …"),
+        ("diff.unchanged-values", "… {count} unchanged values …"),
+        ("diff.unchanged-values.one", "… {count} unchanged value …"),
+        ("diff.unchanged-elements", "… {count} unchanged elements …"),
+        (
+            "diff.unchanged-elements.one",
+            "… {count} unchanged element …",
+        ),
+        ("diff.unchanged-rows", "… {count} unchanged rows …"),
+        ("diff.unchanged-rows.one", "… {count} unchanged row …"),
+        ("be-true.pos", "Expected this to be true"),
+        ("be-true.neg", "Expected this to be false"),
+        ("be-some.pos", "to be Some(_)"),
+        ("be-some.neg", "to be None"),
+        ("be-ok.pos", "to be Ok(_)"),
+        ("be-ok.neg", "to be Err(_)"),
+        ("equal.pos", "to equal"),
+        ("equal.neg", "to not equal"),
+        ("eq-casefold.pos", "to equal case-insensitively"),
+        ("eq-casefold.neg", "to not equal case-insensitively"),
+        ("approx-eq.pos", "to approximately equal"),
+        ("approx-eq.neg", "to not approximately equal"),
+        ("be-default.pos", "to be the default value"),
+        ("be-default.neg", "to not be the default value"),
+        ("be-zero.pos", "to be 0"),
+        ("be-zero.neg", "to not be 0"),
+        ("be-gt.pos", "to be greater than"),
+        ("be-gt.neg", "to not be greater than"),
+        ("be-ge.pos", "to be greater than or equal to"),
+        ("be-ge.neg", "to not be greater than or equal to"),
+        ("be-lt.pos", "to be less than"),
+        ("be-lt.neg", "to not be less than"),
+        ("be-le.pos", "to be less than or equal to"),
+        ("be-le.neg", "to not be less than or equal to"),
+        ("be-sorted-asc.pos", "to be sorted in ascending order"),
+        ("be-sorted-asc.neg", "to not be sorted in ascending order"),
+        ("be-sorted-desc.pos", "to be sorted in descending order"),
+        ("be-sorted-desc.neg", "to not be sorted in descending order"),
+        ("be-sorted-by.pos", "to be sorted by the given predicate"),
+        (
+            "be-sorted-by.neg",
+            "to not be sorted by the given predicate",
+        ),
+        ("contain-elements.pos", "to contain elements"),
+        ("contain-elements.neg", "to not contain elements"),
+        ("consist-of.pos", "to consist of elements"),
+        ("consist-of.neg", "to consist of elements"),
+        ("be-in.pos", "to be in"),
+        ("be-in.neg", "to not be in"),
+        ("have-len.pos", "to have length"),
+        ("have-len.neg", "to not have length"),
+        ("be-empty.pos", "to be empty"),
+        ("be-empty.neg", "to not be empty"),
+        ("contain-substr.pos", "to contain the substring"),
+        ("contain-substr.neg", "to not contain the substring"),
+        ("have-prefix.pos", "to have the prefix"),
+        ("have-prefix.neg", "to not have the prefix"),
+        ("have-suffix.pos", "to have the suffix"),
+        ("have-suffix.neg", "to not have the suffix"),
+        ("match-pattern.pos", "to match the pattern"),
+        ("match-pattern.neg", "to not match the pattern"),
+        ("match-regex.pos", "to match the regex"),
+        ("match-regex.neg", "to not match the regex"),
+        (
+            "match-json-schema.pos",
+            "Expected this to match the JSON schema:",
+        ),
+        (
+            "match-json-schema.neg",
+            "Expected this to not match the JSON schema",
+        ),
+        ("be-existing-file.pos", "to exist in the filesystem"),
+        ("be-existing-file.neg", "to not exist in the filesystem"),
+        ("be-regular-file.pos", "to exist and be a regular file"),
+        ("be-regular-file.neg", "to not be a regular file"),
+        ("be-directory.pos", "to exist and be a directory"),
+        ("be-directory.neg", "to not be a directory"),
+        ("be-symlink.pos", "to exist and be a symbolic link"),
+        ("be-symlink.neg", "to not be a symbolic link"),
+    ];
+
+    fn lookup(id: &str) -> Option<&'static str> {
+        Self::MESSAGES
+            .iter()
+            .find(|(message_id, _)| *message_id == id)
+            .map(|(_, message)| *message)
+    }
+}
+
+impl Catalog for EnglishCatalog {
+    fn message(&self, id: &str, count: Option<usize>) -> Option<String> {
+        count
+            .filter(|&count| count == 1)
+            .and_then(|_| Self::lookup(&format!("{}.one", id)))
+            .or_else(|| Self::lookup(id))
+            .map(String::from)
+    }
+
+    fn format_count(&self, count: usize) -> String {
+        group_digits(count)
+    }
+}
+
+/// Install a catalog of messages for failure output.
+///
+/// Messages which the catalog doesn't have fall back to [`EnglishCatalog`]. This setting is global
+/// and affects all threads. See [`Catalog`].
+pub fn set_catalog(catalog: impl Catalog + 'static) {
+    *CATALOG.write().unwrap_or_else(|err| err.into_inner()) = Some(Box::new(catalog));
+}
+
+/// Go back to using only the [`EnglishCatalog`].
+pub fn reset_catalog() {
+    *CATALOG.write().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// Replace the `{name}` parameters in `template` with their values.
+fn fill_params(template: &str, params: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut message = template.to_owned();
+
+    for (name, value) in params {
+        let placeholder = format!("{{{}}}", name);
+
+        if message.contains(&placeholder) {
+            message = message.replace(&placeholder, &value.to_string());
+        }
+    }
+
+    message
+}
+
+/// A message in failure output, which is either a literal string or the ID of a message in the
+/// installed [`Catalog`].
+///
+/// Formatters which accept messages, like [`MismatchFormat`], accept anything that converts into
+/// this type, including the same string types that convert into a [`String`] for a literal
+/// message. Messages with an ID are looked up each time the failure is formatted, so they use
+/// whichever catalog is installed at that time.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::core::Message;
+/// use xpct::format::MismatchFormat;
+///
+/// let format: MismatchFormat<u32, u32> =
+///     MismatchFormat::new(Message::id("equal.pos"), Message::id("equal.neg"));
+/// ```
+///
+/// [`MismatchFormat`]: crate::format::MismatchFormat
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Message {
+    /// A literal message, which is shown as-is.
+    Literal(String),
+
+    /// The ID of a message in the installed catalog. See [`message`].
+    Id(&'static str),
+}
+
+impl Message {
+    /// Create a [`Message`] from the ID of a message in the installed catalog.
+    pub fn id(id: &'static str) -> Self {
+        Self::Id(id)
+    }
+
+    /// Return the text of this message, looking it up in the installed catalog if it's an ID.
+    pub fn resolve(&self) -> Cow<'_, str> {
+        match self {
+            Self::Literal(message) => Cow::Borrowed(message),
+            Self::Id(id) => Cow::Owned(message(id)),
+        }
+    }
+}
+
+impl Default for Message {
+    fn default() -> Self {
+        Self::Literal(String::new())
+    }
+}
+
+impl From<String> for Message {
+    fn from(message: String) -> Self {
+        Self::Literal(message)
+    }
+}
+
+impl From<&str> for Message {
+    fn from(message: &str) -> Self {
+        Self::Literal(message.to_owned())
+    }
+}
+
+impl From<&String> for Message {
+    fn from(message: &String) -> Self {
+        Self::Literal(message.clone())
+    }
+}
+
+impl From<&mut str> for Message {
+    fn from(message: &mut str) -> Self {
+        Self::Literal(message.to_owned())
+    }
+}
+
+impl From<Box<str>> for Message {
+    fn from(message: Box<str>) -> Self {
+        Self::Literal(message.into())
+    }
+}
+
+impl From<Cow<'_, str>> for Message {
+    fn from(message: Cow<'_, str>) -> Self {
+        Self::Literal(message.into_owned())
+    }
+}
+
+impl From<char> for Message {
+    fn from(message: char) -> Self {
+        Self::Literal(message.into())
+    }
+}
+
+/// Return the message with the given ID from the installed catalog.
+///
+/// This falls back to [`EnglishCatalog`] if the installed catalog doesn't have the message, and
+/// to the ID itself if there's no such message at all. See [`Catalog`].
+pub fn message(id: &str) -> String {
+    message_with(id, None, &[])
+}
+
+/// Return the message with the given ID from the installed catalog, filling in its parameters.
+///
+/// If `count` is `Some`, it's used to choose the plural form of the message and fills in the
+/// `{count}` parameter. Other parameters are filled in from `params`. See [`message`].
+///
+/// # Examples
+///
+/// ```
+/// use xpct::core::message_with;
+///
+/// assert_eq!(
+///     message_with("diff.unchanged-lines", Some(1_500), &[]),
+///     "… 1,500 unchanged lines …",
+/// );
+/// ```
+pub fn message_with(
+    id: &str,
+    count: Option<usize>,
+    params: &[(&str, &dyn fmt::Display)],
+) -> String {
+    let catalog = CATALOG.read().unwrap_or_else(|err| err.into_inner());
+    format_message(catalog.as_deref(), id, count, params)
+}
+
+/// Format the message with the given ID from `installed`, falling back to [`EnglishCatalog`].
+fn format_message(
+    installed: Option<&dyn Catalog>,
+    id: &str,
+    count: Option<usize>,
+    params: &[(&str, &dyn fmt::Display)],
+) -> String {
+    let template = installed
+        .and_then(|catalog| catalog.message(id, count))
+        .or_else(|| EnglishCatalog.message(id, count))
+        .unwrap_or_else(|| id.to_owned());
+
+    match count {
+        Some(count) => {
+            // The installed catalog formats the count even if the message falls back to English,
+            // so numbers are formatted consistently.
+            let count = installed.unwrap_or(&EnglishCatalog).format_count(count);
+            let mut all_params: Vec<(&str, &dyn fmt::Display)> = vec![("count", &count)];
+            all_params.extend_from_slice(params);
+            fill_params(&template, &all_params)
+        }
+        None => fill_params(&template, params),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use super::{fill_params, format_message, Catalog, EnglishCatalog, Message};
+
+    /// A catalog which formats numbers with periods, like `1.500`.
+    struct GermanCatalog;
+
+    impl Catalog for GermanCatalog {
+        fn message(&self, id: &str, count: Option<usize>) -> Option<String> {
+            match (id, count) {
+                ("diff.unchanged-lines", Some(1)) => Some(String::from("… {count} Zeile …")),
+                ("diff.unchanged-lines", _) => Some(String::from("… {count} Zeilen …")),
+                _ => None,
+            }
+        }
+
+        fn format_count(&self, count: usize) -> String {
+            EnglishCatalog.format_count(count).replace(',', ".")
+        }
+    }
+
+    #[test]
+    fn messages_convert_from_strings() {
+        let expected = Message::Literal(String::from("to equal"));
+
+        assert_eq!(Message::from("to equal"), expected);
+        assert_eq!(Message::from(String::from("to equal")), expected);
+        assert_eq!(Message::from(&String::from("to equal")), expected);
+        assert_eq!(Message::from(Box::<str>::from("to equal")), expected);
+        assert_eq!(Message::from(Cow::Borrowed("to equal")), expected);
+        assert_eq!(Message::from('!'), Message::Literal(String::from("!")));
+    }
+
+    #[test]
+    fn english_has_unique_ids() {
+        let mut ids = EnglishCatalog::MESSAGES
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+
+        assert_eq!(ids.len(), EnglishCatalog::MESSAGES.len());
+    }
+
+    #[test]
+    fn english_chooses_singular() {
+        assert_eq!(
+            EnglishCatalog
+                .message("diff.unchanged-lines", Some(1))
+                .as_deref(),
+            Some("… {count} unchanged line …"),
+        );
+        assert_eq!(
+            EnglishCatalog
+                .message("diff.unchanged-lines", Some(2))
+                .as_deref(),
+            Some("… {count} unchanged lines …"),
+        );
+    }
+
+    #[test]
+    fn english_returns_none_for_unknown_ids() {
+        assert_eq!(EnglishCatalog.message("not-a-message", None), None);
+    }
+
+    #[test]
+    fn map_chooses_singular() {
+        let catalog = HashMap::from([
+            (String::from("things"), String::from("{count} Dinge")),
+            (String::from("things.one"), String::from("{count} Ding")),
+        ]);
+
+        assert_eq!(
+            catalog.message("things", Some(1)).as_deref(),
+            Some("{count} Ding")
+        );
+        assert_eq!(
+            catalog.message("things", Some(3)).as_deref(),
+            Some("{count} Dinge")
+        );
+        assert_eq!(catalog.message("other", None), None);
+    }
+
+    #[test]
+    fn fills_params() {
+        assert_eq!(
+            fill_params("{count} of {name}", &[("count", &3), ("name", &"these")]),
+            "3 of these",
+        );
+    }
+
+    #[test]
+    fn installed_catalog_formats_counts() {
+        assert_eq!(
+            format_message(
+                Some(&GermanCatalog),
+                "diff.unchanged-lines",
+                Some(1500),
+                &[]
+            ),
+            "… 1.500 Zeilen …",
+        );
+        assert_eq!(
+            format_message(Some(&GermanCatalog), "diff.unchanged-rows", Some(1500), &[]),
+            "… 1.500 unchanged rows …",
+        );
+        assert_eq!(
+            format_message(None, "diff.unchanged-lines", Some(1500), &[]),
+            "… 1,500 unchanged lines …",
+        );
+    }
+
+    #[test]
+    fn default_format_count_has_no_separators() {
+        let catalog = HashMap::from([(String::from("things"), String::from("{count} Dinge"))]);
+
+        assert_eq!(
+            format_message(Some(&catalog), "things", Some(1500), &[]),
+            "1500 Dinge",
+        );
+    }
+
+    #[test]
+    fn resolves_messages() {
+        assert_eq!(Message::from("to equal").resolve(), "to equal");
+        assert_eq!(Message::id("equal.pos").resolve(), "to equal");
+        assert_eq!(Message::id("not-a-message").resolve(), "not-a-message");
+    }
+}
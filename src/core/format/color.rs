@@ -1,7 +1,14 @@
 use bitflags::bitflags;
 
+use super::config::{update_config, with_config};
+
 #[cfg(feature = "color")]
-use colored::{ColoredString, Colorize};
+use {
+    colored::{ColoredString, Colorize},
+    std::env,
+    std::ffi::OsString,
+    std::io::{self, IsTerminal},
+};
 
 bitflags! {
     /// The style of text in a terminal emulator.
@@ -190,3 +197,172 @@ impl OutputStyle {
         self.style.apply(self.color.apply(s.into()))
     }
 }
+
+/// When to emit colors and text styles in failure output.
+///
+/// See [`Config::color`].
+///
+/// [`Config::color`]: crate::core::Config::color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum ColorChoice {
+    /// Decide based on the environment.
+    ///
+    /// Colors are disabled when the [`NO_COLOR`](https://no-color.org/) environment variable is
+    /// set to a non-empty value. Otherwise, they're enabled when the
+    /// [`CLICOLOR_FORCE`](https://bixense.com/clicolors/) environment variable is set to a value
+    /// other than `0`. Otherwise, they're disabled when `CLICOLOR` is set to `0` or when stderr is
+    /// not a terminal, and enabled if it is.
+    #[default]
+    Auto,
+
+    /// Always emit colors and text styles, regardless of the environment.
+    Always,
+
+    /// Never emit colors and text styles, regardless of the environment.
+    Never,
+}
+
+/// Set when to emit colors and text styles in failure output.
+///
+/// This is a shorthand for setting [`Config::color`] with [`update_config`]. This has no effect
+/// when the `color` Cargo feature is disabled.
+///
+/// [`Config::color`]: crate::core::Config::color
+/// [`update_config`]: crate::core::update_config
+pub fn set_color_choice(choice: ColorChoice) {
+    update_config(|config| config.color = choice);
+}
+
+/// Return when to emit colors and text styles in failure output.
+///
+/// See [`Config::color`].
+///
+/// [`Config::color`]: crate::core::Config::color
+pub fn color_choice() -> ColorChoice {
+    with_config(|config| config.color)
+}
+
+/// The environment used to decide whether to emit colors with [`ColorChoice::Auto`].
+#[cfg(feature = "color")]
+#[derive(Debug, Default)]
+struct ColorEnv {
+    no_color: Option<OsString>,
+    clicolor_force: Option<OsString>,
+    clicolor: Option<OsString>,
+    is_terminal: bool,
+}
+
+#[cfg(feature = "color")]
+impl ColorEnv {
+    fn from_env() -> Self {
+        Self {
+            no_color: env::var_os("NO_COLOR"),
+            clicolor_force: env::var_os("CLICOLOR_FORCE"),
+            clicolor: env::var_os("CLICOLOR"),
+            is_terminal: io::stderr().is_terminal(),
+        }
+    }
+
+    fn use_color(&self) -> bool {
+        if self
+            .no_color
+            .as_ref()
+            .is_some_and(|value| !value.is_empty())
+        {
+            return false;
+        }
+
+        if self
+            .clicolor_force
+            .as_ref()
+            .is_some_and(|value| value != "0")
+        {
+            return true;
+        }
+
+        if self.clicolor.as_ref().is_some_and(|value| value == "0") {
+            return false;
+        }
+
+        self.is_terminal
+    }
+}
+
+/// Return whether to emit colors and text styles in failure output.
+#[cfg(feature = "color")]
+pub(super) fn use_color() -> bool {
+    match color_choice() {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => ColorEnv::from_env().use_color(),
+    }
+}
+
+#[cfg(all(test, feature = "color"))]
+mod tests {
+    use super::ColorEnv;
+
+    #[test]
+    fn colors_when_terminal() {
+        let env = ColorEnv {
+            is_terminal: true,
+            ..Default::default()
+        };
+        assert!(env.use_color());
+    }
+
+    #[test]
+    fn no_colors_when_not_terminal() {
+        assert!(!ColorEnv::default().use_color());
+    }
+
+    #[test]
+    fn no_colors_when_no_color_set() {
+        let env = ColorEnv {
+            no_color: Some("1".into()),
+            clicolor_force: Some("1".into()),
+            is_terminal: true,
+            ..Default::default()
+        };
+        assert!(!env.use_color());
+    }
+
+    #[test]
+    fn colors_when_no_color_empty() {
+        let env = ColorEnv {
+            no_color: Some("".into()),
+            is_terminal: true,
+            ..Default::default()
+        };
+        assert!(env.use_color());
+    }
+
+    #[test]
+    fn colors_when_clicolor_force_set() {
+        let env = ColorEnv {
+            clicolor_force: Some("1".into()),
+            ..Default::default()
+        };
+        assert!(env.use_color());
+    }
+
+    #[test]
+    fn no_colors_when_clicolor_force_zero() {
+        let env = ColorEnv {
+            clicolor_force: Some("0".into()),
+            ..Default::default()
+        };
+        assert!(!env.use_color());
+    }
+
+    #[test]
+    fn no_colors_when_clicolor_zero() {
+        let env = ColorEnv {
+            clicolor: Some("0".into()),
+            is_terminal: true,
+            ..Default::default()
+        };
+        assert!(!env.use_color());
+    }
+}
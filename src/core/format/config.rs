@@ -0,0 +1,385 @@
+use std::env;
+use std::sync::{OnceLock, RwLock};
+
+use super::ColorChoice;
+
+/// The default number of spaces per level of indentation in failure output.
+pub const DEFAULT_INDENT: u32 = 4;
+
+/// The default maximum length of a value in failure output, in characters.
+///
+/// See [`Config::max_value_len`].
+pub const DEFAULT_MAX_VALUE_LEN: usize = 4096;
+
+/// The default number of unchanged lines or elements to show around each change in a diff.
+///
+/// See [`Config::diff_context`].
+pub const DEFAULT_DIFF_CONTEXT: usize = 3;
+
+static CONFIG: OnceLock<RwLock<Config>> = OnceLock::new();
+
+/// Global settings for the output of the provided formatters.
+///
+/// The current settings are returned by [`config`], and you can change them with [`set_config`]
+/// or [`update_config`]. These settings are global and affect all threads.
+///
+/// The settings start out as [`Config::from_env`], so each of them can also be set with an
+/// environment variable:
+///
+/// | Environment variable  | Setting                      | Values                            |
+/// | --------------------- | ---------------------------- | --------------------------------- |
+/// | `XPCT_INDENT`         | [`Config::indent`]           | A number of spaces                |
+/// | `XPCT_WHY_SYMBOL`     | [`Config::why_symbol`]       | Any string                        |
+/// | `XPCT_OK_SYMBOL`      | [`Config::ok_symbol`]        | Any string                        |
+/// | `XPCT_FAILED_SYMBOL`  | [`Config::failed_symbol`]    | Any string                        |
+/// | `XPCT_MAX_VALUE_LEN`  | [`Config::max_value_len`]    | A number of characters, or `none` |
+/// | `XPCT_DIFF_CONTEXT`   | [`Config::diff_context`]     | A number of lines, or `none`      |
+/// | `XPCT_COLOR`          | [`Config::color`]            | `auto`, `always`, or `never`      |
+/// | `XPCT_SOFT_WRAP`      | [`Config::soft_wrap`]        | `1` or `0`                        |
+/// | `XPCT_COMPACT`        | [`Config::compact`]          | `1` or `0`                        |
+/// | `XPCT_SOURCE_SNIPPET` | [`Config::source_snippet`]   | A number of lines, or `none`      |
+/// | `XPCT_VERBOSE`        | [`Config::verbose`]          | `1` or `0`                        |
+///
+/// Environment variables with invalid values are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::core::ColorChoice;
+///
+/// xpct::update_config(|config| {
+///     config.indent = 2;
+///     config.failed_symbol = String::from("✗");
+///     config.color = ColorChoice::Never;
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Config {
+    /// The number of spaces per level of indentation.
+    ///
+    /// The default is [`DEFAULT_INDENT`].
+    pub indent: u32,
+
+    /// The symbol printed before the context added by [`why`].
+    ///
+    /// The default is `[why]`.
+    ///
+    /// [`why`]: crate::why
+    pub why_symbol: String,
+
+    /// The symbol printed next to matchers which succeeded, like in the output of [`match_fields`].
+    ///
+    /// The default is `OK`.
+    ///
+    /// [`match_fields`]: crate::match_fields
+    pub ok_symbol: String,
+
+    /// The symbol printed next to matchers which failed, like in the output of [`match_fields`]
+    /// and [`every`].
+    ///
+    /// The default is `FAILED`.
+    ///
+    /// [`match_fields`]: crate::match_fields
+    /// [`every`]: crate::every
+    pub failed_symbol: String,
+
+    /// The maximum length of values in failure output, in characters.
+    ///
+    /// Formatters that print values, like [`MismatchFormat`] and [`ExpectationFormat`], elide the
+    /// middle of values longer than this so that a huge vector or string doesn't bury the rest of
+    /// the failure output. Collections keep their first and last few elements, and strings being
+    /// compared keep a window around the first position where they differ.
+    ///
+    /// If this is `None`, values are never truncated. The default is [`DEFAULT_MAX_VALUE_LEN`].
+    /// Individual formatters can override this limit, but the provided matchers always use this
+    /// one; see [`MismatchFormat::max_len`].
+    ///
+    /// [`MismatchFormat`]: crate::format::MismatchFormat
+    /// [`ExpectationFormat`]: crate::format::ExpectationFormat
+    /// [`MismatchFormat::max_len`]: crate::format::MismatchFormat::max_len
+    pub max_value_len: Option<usize>,
+
+    /// The number of unchanged lines or elements to show around each change in a diff.
+    ///
+    /// This is the default for [`DiffStyle::context`]. If this is `None`, the whole diff is shown.
+    /// The default is [`DEFAULT_DIFF_CONTEXT`].
+    ///
+    /// [`DiffStyle::context`]: crate::format::diff::DiffStyle::context
+    pub diff_context: Option<usize>,
+
+    /// When to emit colors and text styles.
+    ///
+    /// This has no effect when the `color` Cargo feature is disabled. The default is
+    /// [`ColorChoice::Auto`].
+    pub color: ColorChoice,
+
+    /// Whether to soft-wrap long lines to fit the terminal.
+    ///
+    /// When this is enabled, lines which are wider than the terminal are broken at whitespace
+    /// where possible, and the continuation lines are indented to match the start of the line so
+    /// the indentation of the output is preserved. The width of the terminal is taken from the
+    /// `COLUMNS` environment variable if it's set, or else from the terminal attached to stderr.
+    /// Output is never wrapped when stderr is not a terminal and `COLUMNS` is not set.
+    ///
+    /// This is disabled by default.
+    pub soft_wrap: bool,
+
+    /// Whether [`DefaultAssertionFormat`] prints each failure on a single line.
+    ///
+    /// When this is enabled, failures from [`expect!`] look like those from
+    /// [`CompactAssertionFormat`]. This is useful for making the output terser in CI without
+    /// changing any tests. This is disabled by default.
+    ///
+    /// [`DefaultAssertionFormat`]: crate::core::DefaultAssertionFormat
+    /// [`CompactAssertionFormat`]: crate::core::CompactAssertionFormat
+    /// [`expect!`]: crate::expect!
+    pub compact: bool,
+
+    /// The number of lines of source code to show before and after a failing assertion.
+    ///
+    /// When this is `Some`, [`DefaultAssertionFormat`] reads the source file of the assertion and
+    /// prints the lines around it with a caret under the column where it was made, like rustc
+    /// does. Nothing is printed if the source file can't be read, like when its path has been
+    /// remapped with `--remap-path-prefix`.
+    ///
+    /// If this is `None`, no source code is shown. This is the default.
+    ///
+    /// [`DefaultAssertionFormat`]: crate::core::DefaultAssertionFormat
+    pub source_snippet: Option<usize>,
+
+    /// Whether combinator matchers print the matchers which succeeded as well as those which
+    /// failed.
+    ///
+    /// When this is enabled, matchers like [`any`] and [`each`] print every matcher they compose,
+    /// marking each one as OK or FAILED. The matchers which succeeded are described too, if they
+    /// implement [`Match::describe`]. This is useful for debugging flaky tests. This is disabled
+    /// by default.
+    ///
+    /// To enable this for a single assertion, use [`each_verbose`], [`any_verbose`], or
+    /// [`all_verbose`].
+    ///
+    /// [`any`]: crate::any
+    /// [`each`]: crate::each
+    /// [`each_verbose`]: crate::each_verbose
+    /// [`any_verbose`]: crate::any_verbose
+    /// [`all_verbose`]: crate::all_verbose
+    /// [`Match::describe`]: crate::core::Match::describe
+    pub verbose: bool,
+}
+
+impl Config {
+    /// The provided settings, ignoring the environment.
+    ///
+    /// The value returned by this method may change and is not part of the public API.
+    pub fn provided() -> Self {
+        Self {
+            indent: DEFAULT_INDENT,
+            why_symbol: String::from("[why]"),
+            ok_symbol: String::from("OK"),
+            failed_symbol: String::from("FAILED"),
+            max_value_len: Some(DEFAULT_MAX_VALUE_LEN),
+            diff_context: Some(DEFAULT_DIFF_CONTEXT),
+            color: ColorChoice::Auto,
+            soft_wrap: false,
+            compact: false,
+            source_snippet: None,
+            verbose: false,
+        }
+    }
+
+    /// The provided settings, overridden by any `XPCT_*` environment variables.
+    ///
+    /// See [`Config`] for the list of environment variables.
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let mut config = Self::provided();
+
+        if let Some(indent) = var("XPCT_INDENT").and_then(|value| value.trim().parse().ok()) {
+            config.indent = indent;
+        }
+
+        if let Some(symbol) = var("XPCT_WHY_SYMBOL") {
+            config.why_symbol = symbol;
+        }
+
+        if let Some(symbol) = var("XPCT_OK_SYMBOL") {
+            config.ok_symbol = symbol;
+        }
+
+        if let Some(symbol) = var("XPCT_FAILED_SYMBOL") {
+            config.failed_symbol = symbol;
+        }
+
+        if let Some(len) = var("XPCT_MAX_VALUE_LEN").and_then(|value| parse_limit(&value)) {
+            config.max_value_len = len;
+        }
+
+        if let Some(context) = var("XPCT_DIFF_CONTEXT").and_then(|value| parse_limit(&value)) {
+            config.diff_context = context;
+        }
+
+        if let Some(color) = var("XPCT_COLOR").and_then(|value| parse_color(&value)) {
+            config.color = color;
+        }
+
+        if let Some(enabled) = var("XPCT_SOFT_WRAP").and_then(|value| parse_bool(&value)) {
+            config.soft_wrap = enabled;
+        }
+
+        if let Some(enabled) = var("XPCT_COMPACT").and_then(|value| parse_bool(&value)) {
+            config.compact = enabled;
+        }
+
+        if let Some(context) = var("XPCT_SOURCE_SNIPPET").and_then(|value| parse_limit(&value)) {
+            config.source_snippet = context;
+        }
+
+        if let Some(enabled) = var("XPCT_VERBOSE").and_then(|value| parse_bool(&value)) {
+            config.verbose = enabled;
+        }
+
+        config
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::provided()
+    }
+}
+
+/// Parse a number, or `none` for no limit.
+fn parse_limit(value: &str) -> Option<Option<usize>> {
+    match value.trim() {
+        value if value.eq_ignore_ascii_case("none") => Some(None),
+        value => value.parse().ok().map(Some),
+    }
+}
+
+fn parse_color(value: &str) -> Option<ColorChoice> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "auto" => Some(ColorChoice::Auto),
+        "always" => Some(ColorChoice::Always),
+        "never" => Some(ColorChoice::Never),
+        _ => None,
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn global() -> &'static RwLock<Config> {
+    CONFIG.get_or_init(|| RwLock::new(Config::from_env()))
+}
+
+/// Return the current global settings for failure output.
+///
+/// See [`Config`].
+pub fn config() -> Config {
+    with_config(Config::clone)
+}
+
+/// Replace the global settings for failure output.
+///
+/// This setting is global and affects all threads. To change only some settings, use
+/// [`update_config`]. See [`Config`].
+pub fn set_config(config: Config) {
+    *global().write().unwrap_or_else(|err| err.into_inner()) = config;
+}
+
+/// Change some of the global settings for failure output.
+///
+/// This setting is global and affects all threads. See [`Config`].
+///
+/// # Examples
+///
+/// ```
+/// xpct::update_config(|config| {
+///     config.max_value_len = None;
+///     config.diff_context = Some(5);
+/// });
+/// ```
+pub fn update_config(f: impl FnOnce(&mut Config)) {
+    f(&mut global().write().unwrap_or_else(|err| err.into_inner()));
+}
+
+/// Call `f` with the current settings without cloning them.
+pub(crate) fn with_config<T>(f: impl FnOnce(&Config) -> T) -> T {
+    f(&global().read().unwrap_or_else(|err| err.into_inner()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{ColorChoice, Config};
+
+    fn from_vars(vars: &[(&str, &str)]) -> Config {
+        let vars = vars
+            .iter()
+            .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+            .collect::<HashMap<_, _>>();
+
+        Config::from_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn no_vars_is_provided() {
+        assert_eq!(from_vars(&[]), Config::provided());
+    }
+
+    #[test]
+    fn vars_override_provided() {
+        let config = from_vars(&[
+            ("XPCT_INDENT", "2"),
+            ("XPCT_WHY_SYMBOL", "?"),
+            ("XPCT_OK_SYMBOL", "✓"),
+            ("XPCT_FAILED_SYMBOL", "✗"),
+            ("XPCT_MAX_VALUE_LEN", "none"),
+            ("XPCT_DIFF_CONTEXT", "10"),
+            ("XPCT_COLOR", "never"),
+            ("XPCT_SOFT_WRAP", "1"),
+            ("XPCT_COMPACT", "1"),
+            ("XPCT_SOURCE_SNIPPET", "2"),
+            ("XPCT_VERBOSE", "1"),
+        ]);
+
+        assert_eq!(
+            config,
+            Config {
+                indent: 2,
+                why_symbol: String::from("?"),
+                ok_symbol: String::from("✓"),
+                failed_symbol: String::from("✗"),
+                max_value_len: None,
+                diff_context: Some(10),
+                color: ColorChoice::Never,
+                soft_wrap: true,
+                compact: true,
+                source_snippet: Some(2),
+                verbose: true,
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_vars_are_ignored() {
+        let config = from_vars(&[
+            ("XPCT_INDENT", "-1"),
+            ("XPCT_MAX_VALUE_LEN", "lots"),
+            ("XPCT_COLOR", "sometimes"),
+            ("XPCT_SOFT_WRAP", "maybe"),
+        ]);
+
+        assert_eq!(config, Config::provided());
+    }
+}
@@ -1,13 +1,14 @@
 #![cfg(not(feature = "color"))]
 
-// Nothing in this module should have public-facing API documentation. Since the API is identical,
-// we elect to only show the versions of these types with `#[cfg(feature = "color")]` in the API
-// docs, so all the doc comments should go there.
+// Since the API is identical, we elect to only show the versions of these types with
+// `#[cfg(feature = "color")]` in the API docs, so the doc comments should go there. Newer methods
+// copy theirs here too, so they're documented in builds without that feature.
 
 use std::fmt;
 
 use super::color::OutputStyle;
-use super::{strings, Format};
+use super::render::Render;
+use super::{strings, terminal, Format};
 
 #[derive(Debug)]
 pub struct Formatter {
@@ -31,6 +32,15 @@ impl Formatter {
         self.buf.push(c);
     }
 
+    /// Write a hyperlink to the output.
+    ///
+    /// The text is written with the current [`OutputStyle`]. When the terminal supports them, it's
+    /// emitted as an [OSC 8](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feaf)
+    /// hyperlink to `url`. Otherwise, only the text is written.
+    pub fn write_link(&mut self, text: impl AsRef<str>, _url: impl Into<String>) {
+        self.buf.push_str(text.as_ref());
+    }
+
     pub fn write_fmt(&mut self, output: impl Into<FormattedOutput>) {
         self.buf.push_str(&output.into().buf);
     }
@@ -112,8 +122,32 @@ impl FormattedOutput {
         self.indented_inner(prefix, true)
     }
 
+    /// Return a new [`FormattedOutput`] with all of its lines joined into a single line.
+    ///
+    /// Line breaks and the indentation after them are replaced with a single space.
+    pub fn joined(self) -> Self {
+        Self {
+            buf: strings::join_lines(&self.buf),
+        }
+    }
+
+    /// Render this output with the given [`Render`] backend, like to HTML or Markdown.
+    ///
+    /// This always includes the colors and text styles, regardless of [`color_choice`].
+    ///
+    /// [`color_choice`]: crate::core::color_choice
+    pub fn render(&self, renderer: &impl Render) -> String {
+        let mut body = String::new();
+        renderer.render_span(&mut body, &self.buf, &OutputStyle::default());
+        renderer.render_block(body)
+    }
+
+    #[track_caller]
     pub fn fail(&self) -> ! {
-        panic!("\n{}\n", self);
+        match terminal::wrap_width() {
+            Some(width) => panic!("\n{}\n", strings::wrap(&self.buf, width)),
+            None => panic!("\n{}\n", self),
+        }
     }
 }
 
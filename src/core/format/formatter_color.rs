@@ -1,18 +1,13 @@
 #![cfg(feature = "color")]
 
 use std::fmt;
-use std::io::{self, IsTerminal};
 
-use super::strings::{indent_segments, OutputSegment};
+use super::color::use_color;
+use super::render::Render;
+use super::strings::{indent_segments, join_segment_lines, wrap_segments, OutputSegment};
+use super::terminal;
 use super::{Format, OutputStyle};
 
-// Disable colors and text styles if stderr is not a tty.
-fn check_disable_color() {
-    if !io::stderr().is_terminal() {
-        colored::control::set_override(false)
-    }
-}
-
 /// Configuration for formatting with [`Format`].
 ///
 /// This value is passed to [`Format::fmt`] and is used to set various options to configure how the
@@ -43,6 +38,7 @@ impl Formatter {
         let new_current = OutputSegment {
             buf: String::new(),
             style: self.current.style.clone(),
+            link: None,
         };
 
         self.prev
@@ -60,6 +56,21 @@ impl Formatter {
         self.current.buf.push(c);
     }
 
+    /// Write a hyperlink to the output.
+    ///
+    /// The text is written with the current [`OutputStyle`]. When the terminal supports them, it's
+    /// emitted as an [OSC 8](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feaf)
+    /// hyperlink to `url`. Otherwise, only the text is written.
+    pub fn write_link(&mut self, text: impl AsRef<str>, url: impl Into<String>) {
+        let style = self.current.style.clone();
+
+        self.push_segments(vec![OutputSegment {
+            buf: text.as_ref().to_owned(),
+            style,
+            link: Some(url.into()),
+        }]);
+    }
+
     /// Pass some pre-formatted output through to the output.
     ///
     /// This method is often used when writing formatters for matchers which compose other
@@ -141,6 +152,7 @@ impl Formatter {
             OutputSegment {
                 buf: String::new(),
                 style,
+                link: None,
             },
         ));
     }
@@ -203,26 +215,94 @@ impl FormattedOutput {
         self.indented_inner(prefix, true)
     }
 
+    /// Return a new [`FormattedOutput`] with all of its lines joined into a single line.
+    ///
+    /// Line breaks and the indentation after them are replaced with a single space.
+    pub fn joined(self) -> Self {
+        Self {
+            segments: join_segment_lines(self.segments),
+        }
+    }
+
+    /// Render this output with the given [`Render`] backend, like to HTML or Markdown.
+    ///
+    /// This always includes the colors and text styles, regardless of [`color_choice`].
+    ///
+    /// [`color_choice`]: crate::core::color_choice
+    pub fn render(&self, renderer: &impl Render) -> String {
+        let mut body = String::new();
+        let mut spans: Vec<OutputSegment> = Vec::new();
+
+        // Merge adjacent segments with the same style so each span is rendered only once.
+        for segment in self
+            .segments
+            .iter()
+            .filter(|segment| !segment.buf.is_empty())
+        {
+            match spans.last_mut() {
+                Some(last) if last.style == segment.style && last.link == segment.link => {
+                    last.buf.push_str(&segment.buf)
+                }
+                _ => spans.push(segment.clone()),
+            }
+        }
+
+        for span in &spans {
+            match &span.link {
+                Some(url) => renderer.render_link(&mut body, &span.buf, &span.style, url),
+                None => renderer.render_span(&mut body, &span.buf, &span.style),
+            }
+        }
+
+        renderer.render_block(body)
+    }
+
     /// Panic with this output as the error message.
     ///
-    /// This does not print colors or text styles when the [`NO_COLOR`](https://no-color.org/)
-    /// environment variable is set or when stderr is not a tty.
+    /// Whether this prints colors and text styles depends on [`color_choice`]. By default, it
+    /// doesn't print them when the [`NO_COLOR`](https://no-color.org/) environment variable is set
+    /// or when stderr is not a tty, unless the `CLICOLOR_FORCE` environment variable is set.
+    ///
+    /// If [`soft_wrap`] is enabled, long lines are wrapped to fit the terminal.
+    ///
+    /// [`soft_wrap`]: crate::core::soft_wrap
+    /// [`color_choice`]: crate::core::color_choice
+    #[track_caller]
     pub fn fail(&self) -> ! {
         // See CONTRIBUTING.md for an explanation of why we do this.
         if cfg!(debug_screenshot) {
             println!("{}", self);
             std::process::exit(0);
         } else {
-            check_disable_color();
-            panic!("\n{}\n", self);
+            colored::control::set_override(use_color());
+
+            match terminal::wrap_width() {
+                Some(width) => {
+                    let wrapped = Self {
+                        segments: wrap_segments(self.segments.clone(), width),
+                    };
+                    panic!("\n{}\n", wrapped);
+                }
+                None => panic!("\n{}\n", self),
+            }
         }
     }
 }
 
 impl fmt::Display for FormattedOutput {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hyperlinks =
+            colored::control::SHOULD_COLORIZE.should_colorize() && terminal::supports_hyperlinks();
+
         for segment in &self.segments {
-            f.write_fmt(format_args!("{}", segment.style.apply(&segment.buf)))?;
+            match &segment.link {
+                Some(url) if hyperlinks => f.write_fmt(format_args!(
+                    "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\",
+                    url,
+                    segment.style.apply(&segment.buf)
+                ))?,
+                _ => f.write_fmt(format_args!("{}", segment.style.apply(&segment.buf)))?,
+            }
         }
 
         Ok(())
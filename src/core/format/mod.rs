@@ -1,15 +1,43 @@
 mod assertion;
 mod base;
+mod catalog;
 mod color;
+mod config;
 mod formatter;
 mod formatter_color;
 
+mod render;
+mod snippet;
 pub(crate) mod strings;
 pub(crate) mod style;
+pub(crate) mod terminal;
+mod theme;
+#[cfg(feature = "fmt")]
+mod truncate;
+mod value;
 
-pub use assertion::DefaultAssertionFormat;
+pub use assertion::{CompactAssertionFormat, DefaultAssertionFormat};
 pub use base::{AssertionFormat, DispatchFormat, Format, MatcherFormat, NegFormat};
-pub use color::{Color, OutputStyle, TextColor, TextStyle};
+pub use catalog::{
+    message, message_with, reset_catalog, set_catalog, Catalog, EnglishCatalog, Message,
+};
+pub use color::{
+    color_choice, set_color_choice, Color, ColorChoice, OutputStyle, TextColor, TextStyle,
+};
+pub use config::{
+    config, set_config, update_config, Config, DEFAULT_DIFF_CONTEXT, DEFAULT_INDENT,
+    DEFAULT_MAX_VALUE_LEN,
+};
+pub use render::{HtmlRenderer, MarkdownRenderer, PlainRenderer, Render};
+pub use terminal::{set_soft_wrap, soft_wrap};
+pub use theme::{set_theme, theme, Theme};
+pub use value::{DebugValue, DisplayValue, HexValue, PrettyDebugValue, TypeNameValue, ValueFormat};
+
+#[cfg(feature = "fmt")]
+pub use truncate::{max_value_len, set_max_value_len};
+
+#[cfg(feature = "fmt")]
+pub(crate) use truncate::{truncate_debug, truncate_debug_pair};
 
 #[cfg(feature = "color")]
 pub use formatter_color::{FormattedOutput, Formatter};
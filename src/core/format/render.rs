@@ -0,0 +1,353 @@
+use super::{Color, OutputStyle, TextStyle};
+
+/// A backend which renders formatted output as text in some markup language.
+///
+/// Formatters write text with an [`OutputStyle`] to a [`Formatter`], which by default is shown in
+/// the terminal using ANSI escape codes. You can instead render a [`FormattedOutput`] with a
+/// different backend using [`FormattedOutput::render`], like to show failures in an HTML test
+/// report or a comment on a pull request.
+///
+/// The provided backends are:
+///
+/// - [`PlainRenderer`], which renders plain text without any styles.
+/// - [`HtmlRenderer`], which renders HTML with a `<span>` with CSS classes for each style.
+/// - [`MarkdownRenderer`], which renders a block of HTML that's supported by most Markdown
+///   renderers.
+///
+/// When the `color` Cargo feature is disabled, the output has no styles, so these backends only
+/// escape the text.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::core::{Format, FormattedOutput, Formatter, HtmlRenderer};
+///
+/// struct MessageFormat;
+///
+/// impl Format for MessageFormat {
+///     type Value = &'static str;
+///
+///     fn fmt(&self, f: &mut Formatter, value: Self::Value) -> xpct::Result<()> {
+///         f.write_str(value);
+///         Ok(())
+///     }
+/// }
+///
+/// let output = FormattedOutput::new("Expected a <value>.", MessageFormat)?;
+///
+/// assert_eq!(
+///     output.render(&HtmlRenderer::new()),
+///     "<pre class=\"xpct\">Expected a &lt;value&gt;.</pre>",
+/// );
+/// # Ok::<(), xpct::Error>(())
+/// ```
+///
+/// [`Formatter`]: crate::core::Formatter
+/// [`FormattedOutput`]: crate::core::FormattedOutput
+/// [`FormattedOutput::render`]: crate::core::FormattedOutput::render
+pub trait Render {
+    /// Write a span of `text` with the given `style` to `out`.
+    fn render_span(&self, out: &mut String, text: &str, style: &OutputStyle);
+
+    /// Write a span of `text` with the given `style` which links to `url` to `out`.
+    ///
+    /// By default, this ignores the link and calls [`render_span`].
+    ///
+    /// [`render_span`]: crate::core::Render::render_span
+    fn render_link(&self, out: &mut String, text: &str, style: &OutputStyle, url: &str) {
+        let _ = url;
+        self.render_span(out, text, style);
+    }
+
+    /// Wrap the rendered spans in whatever markup is needed to make a complete block.
+    ///
+    /// By default, this returns `body` unchanged.
+    fn render_block(&self, body: String) -> String {
+        body
+    }
+}
+
+/// A [`Render`] backend which renders plain text without any styles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlainRenderer;
+
+impl Render for PlainRenderer {
+    fn render_span(&self, out: &mut String, text: &str, _: &OutputStyle) {
+        out.push_str(text);
+    }
+}
+
+/// Escape the characters in `text` which are special in HTML.
+fn escape_html(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// The names of the text styles, as used in CSS class names.
+const TEXT_STYLE_NAMES: [(TextStyle, &str); 6] = [
+    (TextStyle::BOLD, "bold"),
+    (TextStyle::UNDERLINE, "underline"),
+    (TextStyle::DIMMED, "dimmed"),
+    (TextStyle::ITALIC, "italic"),
+    (TextStyle::STRIKETHROUGH, "strikethrough"),
+    (TextStyle::REVERSED, "reversed"),
+];
+
+/// The name of the color, as used in CSS class names, or `None` if it's an RGB color.
+fn color_name(color: Color) -> Option<&'static str> {
+    Some(match color {
+        Color::Black => "black",
+        Color::Red => "red",
+        Color::Green => "green",
+        Color::Yellow => "yellow",
+        Color::Blue => "blue",
+        Color::Magenta => "magenta",
+        Color::Cyan => "cyan",
+        Color::White => "white",
+        Color::BrightBlack => "bright-black",
+        Color::BrightRed => "bright-red",
+        Color::BrightGreen => "bright-green",
+        Color::BrightYellow => "bright-yellow",
+        Color::BrightBlue => "bright-blue",
+        Color::BrightMagenta => "bright-magenta",
+        Color::BrightCyan => "bright-cyan",
+        Color::BrightWhite => "bright-white",
+        Color::Rgb(..) => return None,
+    })
+}
+
+/// The provided stylesheet for [`HtmlRenderer`], where `{prefix}` is the class prefix.
+const HTML_STYLESHEET: &str = "\
+.{prefix}-bold { font-weight: bold; }
+.{prefix}-underline { text-decoration: underline; }
+.{prefix}-dimmed { opacity: 0.7; }
+.{prefix}-italic { font-style: italic; }
+.{prefix}-strikethrough { text-decoration: line-through; }
+.{prefix}-reversed { filter: invert(100%); }
+.{prefix}-fg-black { color: #000000; }
+.{prefix}-fg-red { color: #cd3131; }
+.{prefix}-fg-green { color: #0dbc79; }
+.{prefix}-fg-yellow { color: #e5e510; }
+.{prefix}-fg-blue { color: #2472c8; }
+.{prefix}-fg-magenta { color: #bc3fbc; }
+.{prefix}-fg-cyan { color: #11a8cd; }
+.{prefix}-fg-white { color: #e5e5e5; }
+.{prefix}-fg-bright-black { color: #666666; }
+.{prefix}-fg-bright-red { color: #f14c4c; }
+.{prefix}-fg-bright-green { color: #23d18b; }
+.{prefix}-fg-bright-yellow { color: #f5f543; }
+.{prefix}-fg-bright-blue { color: #3b8eea; }
+.{prefix}-fg-bright-magenta { color: #d670d6; }
+.{prefix}-fg-bright-cyan { color: #29b8db; }
+.{prefix}-fg-bright-white { color: #e5e5e5; }
+";
+
+/// A [`Render`] backend which renders HTML.
+///
+/// The output is a `<pre>` element, and each piece of styled text is wrapped in a `<span>` with a
+/// CSS class for each text style and color, like `xpct-bold` or `xpct-fg-red`. Background colors
+/// use classes like `xpct-bg-red`, and RGB colors are set with an inline `style` attribute.
+///
+/// You can use [`HtmlRenderer::stylesheet`] to get CSS for these classes, or style them yourself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlRenderer {
+    class_prefix: String,
+}
+
+impl HtmlRenderer {
+    /// Create a new [`HtmlRenderer`] which uses the class prefix `xpct`.
+    pub fn new() -> Self {
+        Self::with_class_prefix("xpct")
+    }
+
+    /// Create a new [`HtmlRenderer`] which uses the given prefix for CSS class names.
+    pub fn with_class_prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            class_prefix: prefix.into(),
+        }
+    }
+
+    /// Return a CSS stylesheet for the classes used by this renderer.
+    ///
+    /// This only styles text styles and foreground colors.
+    pub fn stylesheet(&self) -> String {
+        HTML_STYLESHEET.replace("{prefix}", &self.class_prefix)
+    }
+
+    fn classes(&self, style: &OutputStyle) -> (Vec<String>, Vec<String>) {
+        let mut classes = Vec::new();
+        let mut inline_styles = Vec::new();
+
+        for (text_style, name) in TEXT_STYLE_NAMES {
+            if style.style.contains(text_style) {
+                classes.push(format!("{}-{}", self.class_prefix, name));
+            }
+        }
+
+        for (color, layer, property) in [
+            (style.color.fg, "fg", "color"),
+            (style.color.bg, "bg", "background-color"),
+        ] {
+            match color {
+                Some(Color::Rgb(r, g, b)) => {
+                    inline_styles.push(format!("{}: rgb({}, {}, {})", property, r, g, b))
+                }
+                Some(color) => {
+                    if let Some(name) = color_name(color) {
+                        classes.push(format!("{}-{}-{}", self.class_prefix, layer, name));
+                    }
+                }
+                None => {}
+            }
+        }
+
+        (classes, inline_styles)
+    }
+}
+
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for HtmlRenderer {
+    fn render_span(&self, out: &mut String, text: &str, style: &OutputStyle) {
+        let (classes, inline_styles) = self.classes(style);
+
+        if classes.is_empty() && inline_styles.is_empty() {
+            escape_html(out, text);
+            return;
+        }
+
+        out.push_str("<span");
+
+        if !classes.is_empty() {
+            out.push_str(&format!(" class=\"{}\"", classes.join(" ")));
+        }
+
+        if !inline_styles.is_empty() {
+            out.push_str(&format!(" style=\"{}\"", inline_styles.join("; ")));
+        }
+
+        out.push('>');
+        escape_html(out, text);
+        out.push_str("</span>");
+    }
+
+    fn render_link(&self, out: &mut String, text: &str, style: &OutputStyle, url: &str) {
+        out.push_str("<a href=\"");
+        escape_html(out, url);
+        out.push_str("\">");
+        self.render_span(out, text, style);
+        out.push_str("</a>");
+    }
+
+    fn render_block(&self, body: String) -> String {
+        format!("<pre class=\"{}\">{}</pre>", self.class_prefix, body)
+    }
+}
+
+/// The HTML tags used by [`MarkdownRenderer`] for each text style.
+const MARKDOWN_TAGS: [(TextStyle, &str); 5] = [
+    (TextStyle::BOLD, "b"),
+    (TextStyle::ITALIC, "i"),
+    (TextStyle::UNDERLINE, "ins"),
+    (TextStyle::STRIKETHROUGH, "del"),
+    (TextStyle::REVERSED, "mark"),
+];
+
+/// A [`Render`] backend which renders Markdown, like for a comment on a pull request.
+///
+/// Markdown has no syntax for styled text that preserves whitespace, so this renders a `<pre>`
+/// block using only the HTML tags that Markdown renderers like GitHub's allow: `<b>`, `<i>`,
+/// `<ins>`, `<del>`, and `<mark>`. Colors can't be shown, but the provided [`Theme`]s never use
+/// color as the only way to tell things apart.
+///
+/// [`Theme`]: crate::core::Theme
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MarkdownRenderer;
+
+impl Render for MarkdownRenderer {
+    fn render_span(&self, out: &mut String, text: &str, style: &OutputStyle) {
+        let tags = MARKDOWN_TAGS
+            .iter()
+            .filter(|(text_style, _)| style.style.contains(*text_style))
+            .map(|(_, tag)| *tag)
+            .collect::<Vec<_>>();
+
+        for tag in &tags {
+            out.push_str(&format!("<{}>", tag));
+        }
+
+        escape_html(out, text);
+
+        for tag in tags.iter().rev() {
+            out.push_str(&format!("</{}>", tag));
+        }
+    }
+
+    fn render_block(&self, body: String) -> String {
+        format!("<pre>\n{}\n</pre>\n", body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HtmlRenderer, MarkdownRenderer, PlainRenderer, Render};
+    use crate::core::{Color, OutputStyle, TextColor, TextStyle};
+
+    fn style() -> OutputStyle {
+        OutputStyle {
+            style: TextStyle::BOLD | TextStyle::UNDERLINE,
+            color: TextColor {
+                fg: Some(Color::BrightRed),
+                bg: Some(Color::Rgb(1, 2, 3)),
+            },
+        }
+    }
+
+    fn render(renderer: &impl Render, style: &OutputStyle) -> String {
+        let mut out = String::new();
+        renderer.render_span(&mut out, "a < b", style);
+        renderer.render_block(out)
+    }
+
+    #[test]
+    fn renders_plain_text() {
+        assert_eq!(render(&PlainRenderer, &style()), "a < b");
+    }
+
+    #[test]
+    fn renders_html() {
+        assert_eq!(
+            render(&HtmlRenderer::new(), &style()),
+            "<pre class=\"xpct\"><span class=\"xpct-bold xpct-underline xpct-fg-bright-red\" \
+             style=\"background-color: rgb(1, 2, 3)\">a &lt; b</span></pre>",
+        );
+    }
+
+    #[test]
+    fn renders_html_without_style() {
+        assert_eq!(
+            render(&HtmlRenderer::new(), &OutputStyle::default()),
+            "<pre class=\"xpct\">a &lt; b</pre>",
+        );
+    }
+
+    #[test]
+    fn renders_markdown() {
+        assert_eq!(
+            render(&MarkdownRenderer, &style()),
+            "<pre>\n<b><ins>a &lt; b</ins></b>\n</pre>\n",
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::fs;
+
+use super::{strings, style, terminal, Formatter};
+use crate::core::FileLocation;
+
+/// The number of columns a tab is expanded to in source snippets.
+const TAB_WIDTH: usize = 4;
+
+/// A few lines of source code around an assertion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SourceSnippet {
+    /// The line numbers and text of each line in the snippet, with tabs expanded.
+    lines: Vec<(u32, String)>,
+
+    /// The line number of the assertion.
+    line: u32,
+
+    /// The number of columns before the caret on the line of the assertion.
+    caret_offset: usize,
+}
+
+/// Expand the tabs in `line` to spaces.
+fn expand_tabs(line: &str) -> String {
+    line.replace('\t', &strings::whitespace(TAB_WIDTH))
+}
+
+impl SourceSnippet {
+    /// Pick out the lines within `context` lines of `location` from `source`.
+    ///
+    /// This returns `None` if the location is not in the source.
+    fn from_source(source: &str, location: &FileLocation, context: usize) -> Option<Self> {
+        let index = (location.line as usize).checked_sub(1)?;
+        let first = index.saturating_sub(context);
+
+        let lines = source
+            .lines()
+            .enumerate()
+            .skip(first)
+            .take(index - first + context + 1)
+            .map(|(i, line)| (i as u32 + 1, expand_tabs(line.trim_end())))
+            .collect::<Vec<_>>();
+
+        let assertion_line = source.lines().nth(index)?;
+
+        // Columns from `column!()` count characters and start at 1.
+        let prefix = assertion_line
+            .chars()
+            .take((location.column as usize).saturating_sub(1))
+            .collect::<String>();
+
+        Some(Self {
+            lines,
+            line: location.line,
+            caret_offset: expand_tabs(&prefix).chars().count(),
+        })
+    }
+
+    /// Read the lines within `context` lines of `location` from its source file.
+    ///
+    /// This returns `None` if the file can't be found or read, like when the path has been
+    /// remapped with `--remap-path-prefix`.
+    pub fn read(location: &FileLocation, context: usize) -> Option<Self> {
+        let path = terminal::find_source_file(&location.file)?;
+        let source = fs::read_to_string(path).ok()?;
+
+        Self::from_source(&source, location, context)
+    }
+
+    /// Format this snippet like a rustc diagnostic, with a caret under the assertion.
+    pub fn fmt(&self, f: &mut Formatter) {
+        let number_width = self
+            .lines
+            .last()
+            .map_or(1, |(number, _)| number.to_string().len());
+
+        let write_gutter = |f: &mut Formatter, number: Option<u32>| {
+            f.set_style(style::info());
+
+            match number {
+                Some(number) => f.write_str(format!("{:>number_width$} |", number)),
+                None => f.write_str(format!("{} |", strings::whitespace(number_width))),
+            }
+
+            f.reset_style();
+        };
+
+        write_gutter(f, None);
+
+        for (number, text) in &self.lines {
+            f.write_char('\n');
+            write_gutter(f, Some(*number));
+
+            if !text.is_empty() {
+                f.write_char(' ');
+                f.write_str(text);
+            }
+
+            if *number == self.line {
+                f.write_char('\n');
+                write_gutter(f, None);
+                f.write_char(' ');
+                f.write_str(strings::whitespace(self.caret_offset));
+                f.set_style(style::bad());
+                f.write_char('^');
+                f.reset_style();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SourceSnippet;
+    use crate::core::FileLocation;
+
+    const SOURCE: &str = "fn main() {\n\tlet value = 1;\n\texpect!(value).to(equal(2));\n}\n";
+
+    fn location(line: u32, column: u32) -> FileLocation {
+        FileLocation {
+            file: String::from("src/main.rs"),
+            line,
+            column,
+        }
+    }
+
+    #[test]
+    fn picks_lines_around_assertion() {
+        let snippet = SourceSnippet::from_source(SOURCE, &location(3, 2), 1).unwrap();
+
+        assert_eq!(
+            snippet.lines,
+            vec![
+                (2, String::from("    let value = 1;")),
+                (3, String::from("    expect!(value).to(equal(2));")),
+                (4, String::from("}")),
+            ],
+        );
+    }
+
+    #[test]
+    fn caret_offset_accounts_for_tabs() {
+        let snippet = SourceSnippet::from_source(SOURCE, &location(3, 2), 0).unwrap();
+        assert_eq!(snippet.caret_offset, 4);
+    }
+
+    #[test]
+    fn clamps_context_at_start_of_file() {
+        let snippet = SourceSnippet::from_source(SOURCE, &location(1, 1), 2).unwrap();
+        assert_eq!(
+            snippet.lines.iter().map(|(n, _)| *n).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn none_when_line_is_out_of_range() {
+        assert_eq!(
+            SourceSnippet::from_source(SOURCE, &location(10, 1), 2),
+            None
+        );
+        assert_eq!(SourceSnippet::from_source(SOURCE, &location(0, 1), 2), None);
+    }
+}
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::ops::Range;
 
 const PREFIX_CACHE: &str = "                                                                ";
 
@@ -12,6 +13,17 @@ mod with_color {
     pub struct OutputSegment {
         pub buf: String,
         pub style: OutputStyle,
+        pub link: Option<String>,
+    }
+
+    impl OutputSegment {
+        /// Create a new unstyled segment.
+        pub fn plain(buf: impl Into<String>) -> Self {
+            Self {
+                buf: buf.into(),
+                ..Default::default()
+            }
+        }
     }
 
     /// Indent each line of the string represented by the given list of styled segments.
@@ -29,10 +41,7 @@ mod with_color {
         // Write the indentation spaces with no formatting. Even though colors won't appear on
         // whitespace, some text styles will.
         if !hanging {
-            new_segments.push(OutputSegment {
-                buf: prefix.to_string(),
-                style: OutputStyle::default(),
-            });
+            new_segments.push(OutputSegment::plain(prefix));
         }
 
         let non_empty_segments = segments
@@ -67,13 +76,11 @@ mod with_color {
                 new_segments.push(OutputSegment {
                     buf: owned_line,
                     style: segment.style.clone(),
+                    link: segment.link.clone(),
                 });
 
                 if (!is_last_segment || !is_last_line_in_segment) && needs_newline {
-                    new_segments.push(OutputSegment {
-                        buf: prefix.to_owned(),
-                        style: OutputStyle::default(),
-                    });
+                    new_segments.push(OutputSegment::plain(prefix));
                 }
             }
         }
@@ -82,6 +89,93 @@ mod with_color {
 
         new_segments
     }
+
+    /// Soft-wrap each line of the string represented by the given list of styled segments so it
+    /// fits in `width` columns.
+    ///
+    /// See [`wrap_line`].
+    ///
+    /// [`wrap_line`]: super::wrap_line
+    pub fn wrap_segments(segments: Vec<OutputSegment>, width: usize) -> Vec<OutputSegment> {
+        let mut new_segments = Vec::with_capacity(segments.len());
+
+        // The segments in the current line, split at newlines.
+        let mut line = Vec::new();
+
+        for segment in segments {
+            let mut parts = segment.buf.split('\n').peekable();
+
+            while let Some(part) = parts.next() {
+                line.push(OutputSegment {
+                    buf: part.to_owned(),
+                    style: segment.style.clone(),
+                    link: segment.link.clone(),
+                });
+
+                if parts.peek().is_some() {
+                    wrap_segment_line(&mut new_segments, std::mem::take(&mut line), width);
+                    new_segments.push(OutputSegment::plain("\n"));
+                }
+            }
+        }
+
+        wrap_segment_line(&mut new_segments, line, width);
+
+        new_segments
+    }
+
+    /// Join the lines of `segments` into a single line.
+    ///
+    /// See [`LineJoiner`].
+    ///
+    /// [`LineJoiner`]: super::LineJoiner
+    pub fn join_segment_lines(segments: Vec<OutputSegment>) -> Vec<OutputSegment> {
+        let mut joiner = super::LineJoiner::default();
+
+        segments
+            .into_iter()
+            .map(|segment| {
+                let mut buf = String::with_capacity(segment.buf.len());
+                joiner.push(&mut buf, &segment.buf);
+
+                OutputSegment { buf, ..segment }
+            })
+            .filter(|segment| !segment.buf.is_empty())
+            .collect()
+    }
+
+    fn wrap_segment_line(out: &mut Vec<OutputSegment>, line: Vec<OutputSegment>, width: usize) {
+        let text = line
+            .iter()
+            .map(|segment| segment.buf.as_str())
+            .collect::<String>();
+
+        let (indent, pieces) = super::wrap_line(&text, width);
+
+        for (piece_index, piece) in pieces.into_iter().enumerate() {
+            if piece_index > 0 {
+                out.push(OutputSegment::plain(format!("\n{}", indent)));
+            }
+
+            let mut offset = 0;
+
+            for segment in &line {
+                let segment_range = offset..offset + segment.buf.len();
+                offset = segment_range.end;
+
+                let start = piece.start.max(segment_range.start);
+                let end = piece.end.min(segment_range.end);
+
+                if start < end {
+                    out.push(OutputSegment {
+                        buf: text[start..end].to_owned(),
+                        style: segment.style.clone(),
+                        link: segment.link.clone(),
+                    });
+                }
+            }
+        }
+    }
 }
 
 #[cfg(feature = "color")]
@@ -115,6 +209,22 @@ mod with_fmt {
 #[cfg(feature = "fmt")]
 pub use with_fmt::*;
 
+/// Format a count with thousands separators, like `1,482`.
+pub fn group_digits(count: usize) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            formatted.push(',');
+        }
+
+        formatted.push(digit);
+    }
+
+    formatted
+}
+
 /// Indent each line by the given number of spaces.
 #[cfg(any(not(feature = "color"), test))]
 pub fn indent<'a>(s: &'a str, prefix: &'a str, hanging: bool) -> Cow<'a, str> {
@@ -146,6 +256,138 @@ pub fn indent<'a>(s: &'a str, prefix: &'a str, hanging: bool) -> Cow<'a, str> {
     Cow::Owned(result)
 }
 
+/// The fewest columns a wrapped line can have after its indentation before we stop wrapping it.
+const MIN_WRAP_WIDTH: usize = 20;
+
+/// Split a line which is longer than `width` columns into pieces which fit, breaking at whitespace
+/// where possible.
+///
+/// Each piece after the first should be indented by the leading whitespace of the line to make a
+/// hanging indent, so this returns that whitespace along with the byte range of each piece.
+pub fn wrap_line(line: &str, width: usize) -> (&str, Vec<Range<usize>>) {
+    let indent_len = line.len() - line.trim_start_matches([' ', '\t']).len();
+    let indent = &line[..indent_len];
+    let wrapped_width = width.saturating_sub(indent.chars().count());
+
+    if line.chars().count() <= width || wrapped_width < MIN_WRAP_WIDTH {
+        return (indent, std::iter::once(0..line.len()).collect());
+    }
+
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut available = width;
+
+    loop {
+        let rest = &line[start..];
+
+        let end = match rest.char_indices().nth(available) {
+            Some((index, _)) => start + index,
+            None => {
+                pieces.push(start..line.len());
+                break;
+            }
+        };
+
+        // Don't break inside the indentation of the first line.
+        let break_at = line[start..end]
+            .rfind(' ')
+            .map(|index| start + index)
+            .filter(|&index| index > indent_len && index > start);
+
+        match break_at {
+            Some(index) => {
+                pieces.push(start..index);
+                start = index + 1;
+            }
+            None => {
+                pieces.push(start..end);
+                start = end;
+            }
+        }
+
+        available = wrapped_width;
+    }
+
+    (indent, pieces)
+}
+
+/// Soft-wrap each line of `s` so it fits in `width` columns.
+///
+/// See [`wrap_line`].
+#[cfg(not(feature = "color"))]
+pub fn wrap(s: &str, width: usize) -> String {
+    let mut result = String::with_capacity(s.len());
+
+    for (line_index, line) in s.split('\n').enumerate() {
+        if line_index > 0 {
+            result.push('\n');
+        }
+
+        let (indent, pieces) = wrap_line(line, width);
+
+        for (piece_index, piece) in pieces.into_iter().enumerate() {
+            if piece_index > 0 {
+                result.push('\n');
+                result.push_str(indent);
+            }
+
+            result.push_str(&line[piece]);
+        }
+    }
+
+    result
+}
+
+/// Joins text onto a single line, one piece at a time.
+///
+/// Each run of whitespace which contains a newline is replaced with a single space, and leading and
+/// trailing whitespace is removed. Other whitespace is kept as-is.
+#[derive(Debug, Default)]
+pub struct LineJoiner {
+    pending: String,
+    has_newline: bool,
+    started: bool,
+}
+
+impl LineJoiner {
+    /// Append the joined form of `s` to `out`.
+    ///
+    /// Whitespace at the end of `s` is held back until the next non-whitespace character, since
+    /// it may turn out to be part of a line break.
+    pub fn push(&mut self, out: &mut String, s: &str) {
+        for c in s.chars() {
+            if c.is_whitespace() {
+                self.has_newline |= c == '\n';
+                self.pending.push(c);
+                continue;
+            }
+
+            if self.started && !self.pending.is_empty() {
+                if self.has_newline {
+                    out.push(' ');
+                } else {
+                    out.push_str(&self.pending);
+                }
+            }
+
+            self.pending.clear();
+            self.has_newline = false;
+            self.started = true;
+            out.push(c);
+        }
+    }
+}
+
+/// Join the lines of `s` into a single line.
+///
+/// See [`LineJoiner`].
+#[cfg(not(feature = "color"))]
+pub fn join_lines(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    LineJoiner::default().push(&mut result, s);
+    result
+}
+
 /// Return a string of whitespace of the given length.
 ///
 /// You can use this for indenting text with whitespace via [`Formatter::indented`] and
@@ -164,6 +406,81 @@ pub fn whitespace(spaces: usize) -> Cow<'static, str> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn group_digits_with_thousands_separators() {
+        assert_eq!(group_digits(7), "7");
+        assert_eq!(group_digits(1482), "1,482");
+        assert_eq!(group_digits(1234567), "1,234,567");
+    }
+
+    fn wrapped(line: &str, width: usize) -> Vec<String> {
+        let (indent, pieces) = wrap_line(line, width);
+        pieces
+            .into_iter()
+            .enumerate()
+            .map(|(i, piece)| {
+                if i == 0 {
+                    line[piece].to_owned()
+                } else {
+                    format!("{}{}", indent, &line[piece])
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wrap_line_which_fits() {
+        assert_eq!(wrapped("    short line", 40), vec!["    short line"]);
+    }
+
+    #[test]
+    fn wrap_line_at_whitespace_with_hanging_indent() {
+        let line = "    the quick brown fox jumps over the lazy dog";
+        assert_eq!(
+            wrapped(line, 30),
+            vec!["    the quick brown fox jumps", "    over the lazy dog",],
+        );
+    }
+
+    #[test]
+    fn wrap_line_without_whitespace() {
+        let line = "a".repeat(50);
+        assert_eq!(
+            wrapped(&line, 20),
+            vec!["a".repeat(20), "a".repeat(20), "a".repeat(10)],
+        );
+    }
+
+    #[test]
+    fn wrap_line_does_not_wrap_when_too_narrow() {
+        let line = format!("{}{}", " ".repeat(30), "a".repeat(20));
+        assert_eq!(wrapped(&line, 40), vec![line.clone()]);
+    }
+
+    fn joined(pieces: &[&str]) -> String {
+        let mut joiner = LineJoiner::default();
+        let mut out = String::new();
+
+        for piece in pieces {
+            joiner.push(&mut out, piece);
+        }
+
+        out
+    }
+
+    #[test]
+    fn join_lines_collapses_line_breaks() {
+        assert_eq!(
+            joined(&["\nExpected:\n    1\n", "to  equal:\n    2\n"]),
+            "Expected: 1 to  equal: 2",
+        );
+    }
+
+    #[test]
+    fn join_lines_keeps_whitespace_between_pieces() {
+        assert_eq!(joined(&["a ", " b", "\n"]), "a  b");
+    }
+
     #[test]
     fn indent_when_the_indent_len_is_zero() {
         let input = "line 1\nline 2\n line 3\n";
@@ -2,23 +2,67 @@
 
 use std::borrow::Cow;
 
-use super::{strings, Color, OutputStyle, TextColor, TextStyle};
+use super::catalog::message;
+use super::config::with_config;
+use super::theme::with_theme;
+use super::{strings, OutputStyle, TextStyle};
+use crate::ErrorKind;
 
-pub const WHY_SYMBOL: &str = "[why]";
-pub const OK_MSG: &str = "OK";
-pub const FAILED_MSG: &str = "FAILED";
-pub const INDENT_LEN: u32 = 4;
+#[cfg(feature = "fmt")]
+use super::catalog::Message;
 
-pub const ALL_OK_HEADER: &str = "Expected all of these to succeed:";
-pub const AT_LESAT_ONE_OK_HEADER: &str = "Expected at least one of these to succeed:";
+#[cfg(feature = "fmt")]
+pub fn all_ok_header() -> Message {
+    Message::id("header.all-ok")
+}
 
-pub const ALL_FIELDS_OK_HEADER: &str = "Expected all of these fields to succeed:";
-pub const AT_LESAT_ONE_FIELD_OK_HEADER: &str = "Expected at least one of these fields to succeed:";
+#[cfg(feature = "fmt")]
+pub fn any_ok_header() -> Message {
+    Message::id("header.any-ok")
+}
 
-pub const AT_LESAT_ONE_NOT_OK_MSG: &str = "Expected at least one of these to fail.";
+#[cfg(feature = "fmt")]
+pub fn all_fields_ok_header() -> Message {
+    Message::id("header.all-fields-ok")
+}
+
+#[cfg(feature = "fmt")]
+pub fn any_field_ok_header() -> Message {
+    Message::id("header.any-field-ok")
+}
+
+#[cfg(feature = "fmt")]
+pub fn any_not_ok_msg() -> Message {
+    Message::id("header.any-not-ok")
+}
+
+pub fn error_header(kind: ErrorKind) -> String {
+    message(match kind {
+        ErrorKind::Input => "error.input",
+        ErrorKind::Io => "error.io",
+        ErrorKind::Panic => "error.panic",
+        _ => "error.other",
+    })
+}
+
+pub fn why_symbol() -> String {
+    with_config(|config| config.why_symbol.clone())
+}
+
+pub fn ok_msg() -> String {
+    with_config(|config| config.ok_symbol.clone())
+}
+
+pub fn failed_msg() -> String {
+    with_config(|config| config.failed_symbol.clone())
+}
+
+pub fn verbose() -> bool {
+    with_config(|config| config.verbose)
+}
 
 pub fn indent_len(levels: u32) -> u32 {
-    INDENT_LEN * levels
+    with_config(|config| config.indent) * levels
 }
 
 pub fn indent(levels: u32) -> Cow<'static, str> {
@@ -26,68 +70,43 @@ pub fn indent(levels: u32) -> Cow<'static, str> {
 }
 
 pub fn important() -> OutputStyle {
-    OutputStyle {
-        style: TextStyle::BOLD,
-        color: Default::default(),
-    }
+    with_theme(|theme| theme.important.clone())
 }
 
 pub fn info() -> OutputStyle {
-    OutputStyle {
-        style: Default::default(),
-        color: TextColor {
-            fg: Some(Color::Cyan),
-            bg: None,
-        },
-    }
+    with_theme(|theme| theme.info.clone())
 }
 
 pub fn bad() -> OutputStyle {
-    OutputStyle {
-        style: TextStyle::BOLD,
-        color: TextColor {
-            fg: Some(Color::Red),
-            bg: None,
-        },
-    }
+    with_theme(|theme| theme.bad.clone())
 }
 
 pub fn failure() -> OutputStyle {
-    OutputStyle {
-        style: TextStyle::BOLD | TextStyle::UNDERLINE,
-        color: TextColor {
-            fg: Some(Color::Red),
-            bg: None,
-        },
-    }
+    with_theme(|theme| theme.failure.clone())
 }
 
 pub fn good() -> OutputStyle {
-    OutputStyle {
-        style: TextStyle::BOLD,
-        color: TextColor {
-            fg: Some(Color::Green),
-            bg: None,
-        },
-    }
+    with_theme(|theme| theme.good.clone())
 }
 
 pub fn success() -> OutputStyle {
+    with_theme(|theme| theme.success.clone())
+}
+
+pub fn index() -> OutputStyle {
+    with_theme(|theme| theme.index.clone())
+}
+
+pub fn inserted(style: TextStyle) -> OutputStyle {
     OutputStyle {
-        style: TextStyle::BOLD | TextStyle::UNDERLINE,
-        color: TextColor {
-            fg: Some(Color::Green),
-            bg: None,
-        },
+        style,
+        color: with_theme(|theme| theme.inserted.clone()),
     }
 }
 
-pub fn index() -> OutputStyle {
+pub fn deleted(style: TextStyle) -> OutputStyle {
     OutputStyle {
-        style: Default::default(),
-        color: TextColor {
-            fg: Some(Color::Yellow),
-            bg: None,
-        },
+        style,
+        color: with_theme(|theme| theme.deleted.clone()),
     }
 }
@@ -0,0 +1,138 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "color")]
+use std::io::IsTerminal;
+
+#[cfg(feature = "fmt")]
+use terminal_size::{terminal_size_of, Width};
+
+use super::config::{update_config, with_config};
+
+/// The width to assume when the width of the terminal can't be determined.
+#[cfg(all(feature = "fmt", feature = "diff"))]
+pub const DEFAULT_WIDTH: usize = 80;
+
+/// Return the width of the terminal in columns.
+///
+/// This respects the `COLUMNS` environment variable if it's set. Otherwise, this returns the width
+/// of the terminal attached to stderr, since that's where failures are printed. Querying the
+/// terminal requires the `fmt` feature; without it, only `COLUMNS` is checked.
+pub fn width() -> Option<usize> {
+    let columns = env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse::<usize>().ok())
+        .filter(|&columns| columns > 0);
+
+    #[cfg(feature = "fmt")]
+    let columns = columns
+        .or_else(|| terminal_size_of(std::io::stderr()).map(|(Width(width), _)| width as usize));
+
+    columns
+}
+
+/// Set whether to soft-wrap long lines in failure output to fit the terminal.
+///
+/// This is a shorthand for setting [`Config::soft_wrap`] with [`update_config`].
+///
+/// [`Config::soft_wrap`]: crate::core::Config::soft_wrap
+/// [`update_config`]: crate::core::update_config
+pub fn set_soft_wrap(enabled: bool) {
+    update_config(|config| config.soft_wrap = enabled);
+}
+
+/// Return whether long lines in failure output are soft-wrapped to fit the terminal.
+///
+/// See [`Config::soft_wrap`].
+///
+/// [`Config::soft_wrap`]: crate::core::Config::soft_wrap
+pub fn soft_wrap() -> bool {
+    with_config(|config| config.soft_wrap)
+}
+
+/// Return the width to soft-wrap failure output to, or `None` if it shouldn't be wrapped.
+pub(crate) fn wrap_width() -> Option<usize> {
+    if soft_wrap() {
+        width()
+    } else {
+        None
+    }
+}
+
+/// The values of `TERM_PROGRAM` for terminals which support OSC 8 hyperlinks.
+#[cfg(feature = "color")]
+const HYPERLINK_TERM_PROGRAMS: [&str; 6] = [
+    "iTerm.app",
+    "WezTerm",
+    "vscode",
+    "ghostty",
+    "Hyper",
+    "terminology",
+];
+
+/// The values of `TERM` for terminals which support OSC 8 hyperlinks.
+#[cfg(feature = "color")]
+const HYPERLINK_TERMS: [&str; 3] = ["xterm-kitty", "alacritty", "foot"];
+
+/// Return whether the terminal attached to stderr supports OSC 8 hyperlinks.
+///
+/// There's no reliable way to query this, so this checks for environment variables set by
+/// terminals which are known to support them. Setting the `FORCE_HYPERLINK` environment variable
+/// to `1` or `0` overrides this.
+#[cfg(feature = "color")]
+pub fn supports_hyperlinks() -> bool {
+    if let Some(force) = env::var_os("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+
+    if env::var_os("CI").is_some() || !std::io::stderr().is_terminal() {
+        return false;
+    }
+
+    let var = |name: &str| env::var(name).unwrap_or_default();
+
+    HYPERLINK_TERM_PROGRAMS.contains(&var("TERM_PROGRAM").as_str())
+        || HYPERLINK_TERMS.contains(&var("TERM").as_str())
+        || env::var_os("WT_SESSION").is_some()
+        || env::var_os("KONSOLE_VERSION").is_some()
+        || env::var_os("DOMTERM").is_some()
+        || var("VTE_VERSION")
+            .parse::<u32>()
+            .is_ok_and(|version| version >= 5000)
+}
+
+/// Find the given source file, if it exists.
+///
+/// Paths from `file!()` are relative to the root of the workspace, which isn't necessarily the
+/// current directory, so this looks for the file relative to each ancestor of the current
+/// directory.
+pub fn find_source_file(file: &str) -> Option<PathBuf> {
+    let path = Path::new(file);
+
+    if path.is_absolute() {
+        return Some(path.to_path_buf());
+    }
+
+    let current_dir = env::current_dir().ok()?;
+
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join(path))
+        .find(|path| path.is_file())
+}
+
+/// Return a `file://` URL for the given source file, if it can be found.
+///
+/// See [`find_source_file`].
+pub fn file_url(file: &str) -> Option<String> {
+    let path = find_source_file(file)?
+        .to_string_lossy()
+        .replace('\\', "/")
+        .replace(' ', "%20");
+
+    if path.starts_with('/') {
+        Some(format!("file://{}", path))
+    } else {
+        Some(format!("file:///{}", path))
+    }
+}
@@ -0,0 +1,187 @@
+use std::sync::RwLock;
+
+use super::{Color, OutputStyle, TextColor, TextStyle};
+
+static THEME: RwLock<Option<Theme>> = RwLock::new(None);
+
+fn fg(style: TextStyle, color: Color) -> OutputStyle {
+    OutputStyle {
+        style,
+        color: TextColor {
+            fg: Some(color),
+            bg: None,
+        },
+    }
+}
+
+/// The colors and text styles used by the provided formatters.
+///
+/// You can change the theme globally with [`set_theme`]. Start from one of the provided themes and
+/// override the styles you want to change.
+///
+/// The provided themes never use color as the only way to tell things apart; failures and
+/// deletions are also bold or underlined, and insertions in diffs are also reversed. Use
+/// [`Theme::colorblind`] for a palette that doesn't rely on telling red from green.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::core::{set_theme, Color, OutputStyle, TextColor, TextStyle, Theme};
+///
+/// let mut theme = Theme::colorblind();
+///
+/// theme.info = OutputStyle {
+///     style: TextStyle::ITALIC,
+///     color: TextColor {
+///         fg: Some(Color::BrightBlack),
+///         bg: None,
+///     },
+/// };
+///
+/// set_theme(theme);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Theme {
+    /// The style for headers and other important text, like "Expected:".
+    pub important: OutputStyle,
+
+    /// The style for secondary information, like the line numbers in diffs.
+    pub info: OutputStyle,
+
+    /// The style for values that caused a failure.
+    pub bad: OutputStyle,
+
+    /// The style for messages that indicate a failure, like "FAILED".
+    pub failure: OutputStyle,
+
+    /// The style for values that were expected.
+    pub good: OutputStyle,
+
+    /// The style for messages that indicate a success, like "OK".
+    pub success: OutputStyle,
+
+    /// The style for indices and field names in the output of combinators.
+    pub index: OutputStyle,
+
+    /// The color of values that were inserted in a diff.
+    pub inserted: TextColor,
+
+    /// The color of values that were deleted in a diff.
+    pub deleted: TextColor,
+}
+
+impl Theme {
+    /// The provided theme, which is used by default.
+    ///
+    /// The value returned by this method may change and is not part of the public API.
+    pub fn provided() -> Self {
+        Self {
+            important: OutputStyle {
+                style: TextStyle::BOLD,
+                color: Default::default(),
+            },
+            info: fg(TextStyle::empty(), Color::Cyan),
+            bad: fg(TextStyle::BOLD, Color::Red),
+            failure: fg(TextStyle::BOLD | TextStyle::UNDERLINE, Color::Red),
+            good: fg(TextStyle::BOLD, Color::Green),
+            success: fg(TextStyle::BOLD | TextStyle::UNDERLINE, Color::Green),
+            index: fg(TextStyle::empty(), Color::Yellow),
+            inserted: TextColor {
+                fg: Some(Color::BrightGreen),
+                bg: None,
+            },
+            deleted: TextColor {
+                fg: Some(Color::BrightRed),
+                bg: None,
+            },
+        }
+    }
+
+    /// A theme which uses bright colors and heavier text styles to stand out more.
+    pub fn high_contrast() -> Self {
+        Self {
+            important: OutputStyle {
+                style: TextStyle::BOLD | TextStyle::UNDERLINE,
+                color: Default::default(),
+            },
+            info: fg(TextStyle::empty(), Color::BrightCyan),
+            bad: fg(TextStyle::BOLD, Color::BrightRed),
+            failure: fg(
+                TextStyle::BOLD | TextStyle::UNDERLINE | TextStyle::REVERSED,
+                Color::BrightRed,
+            ),
+            good: fg(TextStyle::BOLD, Color::BrightGreen),
+            success: fg(
+                TextStyle::BOLD | TextStyle::UNDERLINE | TextStyle::REVERSED,
+                Color::BrightGreen,
+            ),
+            index: fg(TextStyle::BOLD, Color::BrightYellow),
+            inserted: TextColor {
+                fg: Some(Color::BrightGreen),
+                bg: None,
+            },
+            deleted: TextColor {
+                fg: Some(Color::BrightRed),
+                bg: None,
+            },
+        }
+    }
+
+    /// A theme which uses blue and yellow instead of green and red.
+    ///
+    /// This is easier to read for people with red-green color blindness, which is the most common
+    /// kind.
+    pub fn colorblind() -> Self {
+        Self {
+            important: OutputStyle {
+                style: TextStyle::BOLD,
+                color: Default::default(),
+            },
+            info: fg(TextStyle::empty(), Color::Cyan),
+            bad: fg(TextStyle::BOLD, Color::Yellow),
+            failure: fg(TextStyle::BOLD | TextStyle::UNDERLINE, Color::Yellow),
+            good: fg(TextStyle::BOLD, Color::Blue),
+            success: fg(TextStyle::BOLD | TextStyle::UNDERLINE, Color::Blue),
+            index: fg(TextStyle::empty(), Color::Magenta),
+            inserted: TextColor {
+                fg: Some(Color::BrightBlue),
+                bg: None,
+            },
+            deleted: TextColor {
+                fg: Some(Color::BrightYellow),
+                bg: None,
+            },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::provided()
+    }
+}
+
+/// Set the theme used by the provided formatters.
+///
+/// This setting is global and affects all threads. See [`Theme`].
+pub fn set_theme(theme: Theme) {
+    *THEME.write().unwrap_or_else(|err| err.into_inner()) = Some(theme);
+}
+
+/// Return the theme used by the provided formatters.
+///
+/// This is [`Theme::provided`] unless it has been changed with [`set_theme`].
+pub fn theme() -> Theme {
+    with_theme(Theme::clone)
+}
+
+/// Call `f` with the current theme without cloning it.
+pub(crate) fn with_theme<T>(f: impl FnOnce(&Theme) -> T) -> T {
+    let theme = THEME.read().unwrap_or_else(|err| err.into_inner());
+
+    match theme.as_ref() {
+        Some(theme) => f(theme),
+        None => f(&Theme::provided()),
+    }
+}
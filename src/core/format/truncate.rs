@@ -0,0 +1,349 @@
+use std::borrow::Cow;
+
+use super::catalog::message_with;
+use super::config::{update_config, with_config};
+
+/// Set the maximum length of values in failure output, in characters.
+///
+/// Pass `None` to never truncate values. This is a shorthand for setting
+/// [`Config::max_value_len`] with [`update_config`].
+///
+/// [`Config::max_value_len`]: crate::core::Config::max_value_len
+/// [`update_config`]: crate::core::update_config
+pub fn set_max_value_len(len: Option<usize>) {
+    update_config(|config| config.max_value_len = len);
+}
+
+/// Return the maximum length of values in failure output, in characters.
+///
+/// This returns `None` if values are never truncated. See [`Config::max_value_len`].
+///
+/// [`Config::max_value_len`]: crate::core::Config::max_value_len
+pub fn max_value_len() -> Option<usize> {
+    with_config(|config| config.max_value_len)
+}
+
+/// The message for a run of `count` elided elements or characters.
+fn elided_msg(count: usize, id: &str) -> String {
+    message_with(id, Some(count), &[])
+}
+
+/// Split `s` at the commas which aren't nested inside brackets or string literals.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        if in_str {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_str = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_str = true,
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = s[start..].trim();
+    if !last.is_empty() {
+        items.push(last);
+    }
+
+    items
+}
+
+/// Split the `Debug` output of a collection into its prefix (like a struct name), the opening
+/// bracket, the contents, and the closing bracket.
+fn split_collection(s: &str) -> Option<(&str, char, &str, char)> {
+    let open_idx = s.find(['[', '{', '('])?;
+    let prefix = &s[..open_idx];
+
+    if !prefix
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '<' | '>' | ' '))
+    {
+        return None;
+    }
+
+    let open = s[open_idx..].chars().next()?;
+    let close = match open {
+        '[' => ']',
+        '{' => '}',
+        _ => ')',
+    };
+
+    if !s.ends_with(close) {
+        return None;
+    }
+
+    let inner = &s[open_idx + 1..s.len() - 1];
+
+    Some((prefix, open, inner, close))
+}
+
+/// Elide the middle `len - max_len` characters of `s`.
+fn truncate_chars(s: &str, len: usize, max_len: usize) -> String {
+    let head_len = max_len - max_len / 2;
+    let tail_len = max_len / 2;
+
+    let head = s.chars().take(head_len).collect::<String>();
+    let tail_start = s
+        .char_indices()
+        .nth(len - tail_len)
+        .map_or(s.len(), |(i, _)| i);
+
+    format!(
+        "{}{}{}",
+        head,
+        elided_msg(len - head_len - tail_len, "truncate.more-characters"),
+        &s[tail_start..]
+    )
+}
+
+/// Elide the middle of the single-line `Debug` output of a value if it's longer than `max_len`
+/// characters.
+///
+/// If the value looks like a collection, this keeps the first and last few elements, which are
+/// truncated in turn if they're too long themselves. Otherwise, it keeps the first and last few
+/// characters.
+pub fn truncate_debug(s: &str, max_len: Option<usize>) -> Cow<'_, str> {
+    let max_len = match max_len {
+        Some(max_len) => max_len,
+        None => return Cow::Borrowed(s),
+    };
+
+    let len = s.chars().count();
+
+    if len <= max_len {
+        return Cow::Borrowed(s);
+    }
+
+    if let Some((prefix, open, inner, close)) = split_collection(s) {
+        let items = split_top_level(inner);
+        let budget = max_len.saturating_sub(prefix.chars().count() + 2);
+
+        // Look inside wrappers like `Some(...)` for a collection to truncate.
+        if let [item] = items.as_slice() {
+            return Cow::Owned(format!(
+                "{prefix}{open}{}{close}",
+                truncate_debug(item, Some(budget))
+            ));
+        }
+
+        let mut head_len = 0;
+        let mut head_count = 0;
+        for item in &items {
+            let item_len = item.chars().count() + 2;
+            if head_count > 0 && head_len + item_len > budget / 2 {
+                break;
+            }
+            head_len += item_len;
+            head_count += 1;
+        }
+
+        let mut tail_len = 0;
+        let mut tail_count = 0;
+        for item in items[head_count.min(items.len())..].iter().rev() {
+            let item_len = item.chars().count() + 2;
+            if tail_count > 0 && tail_len + item_len > budget - budget / 2 {
+                break;
+            }
+            tail_len += item_len;
+            tail_count += 1;
+        }
+
+        let elided = items.len() - head_count - tail_count;
+
+        if elided > 0 {
+            let is_map = open == '{' && items.iter().all(|item| item.contains(": "));
+            let id = match (is_map, prefix.is_empty()) {
+                (true, false) => "truncate.more-fields",
+                (true, true) => "truncate.more-entries",
+                (false, _) => "truncate.more-elements",
+            };
+
+            // The first and last items are kept even if they don't fit, so truncate them too.
+            let head_budget = Some(budget / 2);
+            let tail_budget = Some(budget - budget / 2);
+
+            let mut parts = items[..head_count]
+                .iter()
+                .map(|item| truncate_debug(item, head_budget))
+                .collect::<Vec<_>>();
+            parts.push(Cow::Owned(elided_msg(elided, id)));
+            parts.extend(
+                items[items.len() - tail_count..]
+                    .iter()
+                    .map(|item| truncate_debug(item, tail_budget)),
+            );
+
+            let separator = if open == '{' { " " } else { "" };
+
+            return Cow::Owned(format!(
+                "{prefix}{open}{separator}{}{separator}{close}",
+                parts.join(", "),
+            ));
+        }
+    }
+
+    Cow::Owned(truncate_chars(s, len, max_len))
+}
+
+/// Elide the parts of two `Debug`-formatted strings that are far from the first position where
+/// they differ, if either is longer than `max_len` characters.
+///
+/// Both strings keep a window of `max_len` characters centred on the first difference. If either
+/// value isn't a string literal, this falls back to [`truncate_debug`].
+pub fn truncate_debug_pair<'a>(
+    actual: &'a str,
+    expected: &'a str,
+    max_len: Option<usize>,
+) -> (Cow<'a, str>, Cow<'a, str>) {
+    let is_str = |s: &str| s.len() >= 2 && s.starts_with('"') && s.ends_with('"');
+
+    let max_len = match max_len {
+        Some(max_len) if is_str(actual) && is_str(expected) => max_len,
+        _ => {
+            return (
+                truncate_debug(actual, max_len),
+                truncate_debug(expected, max_len),
+            )
+        }
+    };
+
+    let first_diff = actual
+        .chars()
+        .zip(expected.chars())
+        .take_while(|(a, e)| a == e)
+        .count();
+
+    let window = |s: &'a str| -> Cow<'a, str> {
+        let len = s.chars().count();
+
+        if len <= max_len {
+            return Cow::Borrowed(s);
+        }
+
+        let start = first_diff.saturating_sub(max_len / 2).min(len - max_len);
+        let end = start + max_len;
+
+        let mut windowed = String::new();
+
+        if start > 0 {
+            windowed.push_str(&elided_msg(start, "truncate.more-characters"));
+        }
+
+        windowed.extend(s.chars().skip(start).take(max_len));
+
+        if end < len {
+            windowed.push_str(&elided_msg(len - end, "truncate.more-characters"));
+        }
+
+        Cow::Owned(windowed)
+    };
+
+    (window(actual), window(expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{truncate_debug, truncate_debug_pair};
+
+    #[test]
+    fn short_values_are_not_truncated() {
+        assert_eq!(truncate_debug("[1, 2, 3]", Some(20)), "[1, 2, 3]");
+        assert_eq!(truncate_debug("[1, 2, 3]", None), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn truncates_middle_of_vec() {
+        let value = format!("{:?}", (0..50_000).collect::<Vec<_>>());
+        assert_eq!(
+            truncate_debug(&value, Some(20)),
+            "[0, 1, 2, … 49,996 more elements …, 49999]",
+        );
+    }
+
+    #[test]
+    fn truncates_middle_of_map() {
+        let value = format!(
+            "{:?}",
+            (0..100)
+                .map(|i| (i, i))
+                .collect::<std::collections::BTreeMap<_, _>>()
+        );
+        assert_eq!(
+            truncate_debug(&value, Some(20)),
+            "{ 0: 0, … 98 more entries …, 99: 99 }",
+        );
+    }
+
+    #[test]
+    fn does_not_split_inside_strings() {
+        let value = format!("{:?}", vec!["a, b"; 10]);
+        assert_eq!(
+            truncate_debug(&value, Some(20)),
+            r#"["a, b", … 8 more elements …, "a, b"]"#,
+        );
+    }
+
+    #[test]
+    fn truncates_collection_inside_wrapper() {
+        let value = format!("{:?}", Some((0..100).collect::<Vec<_>>()));
+        assert_eq!(
+            truncate_debug(&value, Some(20)),
+            "Some([0, 1, … 97 more elements …, 99])",
+        );
+    }
+
+    #[test]
+    fn truncates_huge_elements() {
+        let value = format!("{:?}", vec!["a".repeat(100_000); 3]);
+        let truncated = truncate_debug(&value, Some(4096));
+
+        assert!(truncated.starts_with(r#"["aaa"#));
+        assert!(truncated.contains("… 1 more element …"));
+        assert!(truncated.chars().count() < 4096 + 100);
+    }
+
+    #[test]
+    fn truncates_middle_of_scalar() {
+        let value = format!("{:?}", "a".repeat(100));
+        assert_eq!(
+            truncate_debug(&value, Some(10)),
+            r#""aaaa… 92 more characters …aaaa""#,
+        );
+    }
+
+    #[test]
+    fn windows_strings_around_first_difference() {
+        let actual = format!("{:?}", format!("{}X{}", "a".repeat(100), "a".repeat(100)));
+        let expected = format!("{:?}", "a".repeat(201));
+
+        let (actual, expected) = truncate_debug_pair(&actual, &expected, Some(10));
+
+        assert_eq!(
+            actual,
+            "… 96 more characters …aaaaaXaaaa… 97 more characters …"
+        );
+        assert_eq!(
+            expected,
+            "… 96 more characters …aaaaaaaaaa… 97 more characters …"
+        );
+    }
+}
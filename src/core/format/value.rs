@@ -0,0 +1,271 @@
+use std::any::type_name;
+use std::fmt;
+
+/// The number of bytes per line in the pretty output of [`HexValue`].
+const HEX_BYTES_PER_LINE: usize = 16;
+
+/// A way of rendering values in failure output.
+///
+/// Formatters like [`MismatchFormat`] and [`ExpectationFormat`] render values with their [`Debug`]
+/// implementation by default. Implement this trait to render values of some type differently, and
+/// pass it to the formatter with [`MismatchFormat::with_values`] or
+/// [`ExpectationFormat::with_values`].
+///
+/// This trait is implemented for closures that accept a reference to the value and return a
+/// [`String`], so you can use a closure to render values of a specific type.
+///
+/// # Limitations
+///
+/// - A [`ValueFormat`] is chosen per formatter. There's no way to register one for a type
+///   globally, so it only applies to the matchers you pass it to.
+/// - The provided matchers, like [`equal`], always render values with [`Debug`] and require the
+///   values to implement it. Values of types which don't implement [`Debug`] can't be used with
+///   them, and they don't fall back to rendering the type name.
+///
+/// To render values differently, or to make assertions on types which don't implement [`Debug`],
+/// write your own matcher function which passes a [`ValueFormat`] to the formatter, like in the
+/// examples below.
+///
+/// There are also a few provided implementations:
+///
+/// - [`DebugValue`], which uses [`Debug`] and is the default.
+/// - [`PrettyDebugValue`], which uses the pretty-printed [`Debug`] format `{:#?}`.
+/// - [`DisplayValue`], which uses [`Display`].
+/// - [`HexValue`], which renders bytes as hexadecimal.
+/// - [`TypeNameValue`], which renders only the name of the type, for types that don't implement
+///   [`Debug`].
+///
+/// # Examples
+///
+/// Render a domain ID with its [`Display`] implementation:
+///
+/// ```
+/// use std::fmt;
+///
+/// use xpct::core::{DisplayValue, Matcher};
+/// use xpct::format::MismatchFormat;
+/// use xpct::matchers::equal::EqualMatcher;
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct UserId(u64);
+///
+/// impl fmt::Display for UserId {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "user#{}", self.0)
+///     }
+/// }
+///
+/// pub fn equal_user<'a>(expected: UserId) -> Matcher<'a, UserId, UserId> {
+///     Matcher::new(
+///         EqualMatcher::new(expected),
+///         MismatchFormat::new("to equal", "to not equal").with_values(DisplayValue),
+///     )
+/// }
+/// ```
+///
+/// Make assertions on a type which doesn't implement [`Debug`], rendering only its type name:
+///
+/// ```
+/// use xpct::core::{Matcher, TypeNameValue};
+/// use xpct::format::MismatchFormat;
+/// use xpct::matchers::equal::EqualMatcher;
+///
+/// #[derive(PartialEq, Eq)]
+/// struct Token(u64);
+///
+/// pub fn equal_token<'a>(expected: Token) -> Matcher<'a, Token, Token> {
+///     Matcher::new(
+///         EqualMatcher::new(expected),
+///         MismatchFormat::new("to equal", "to not equal").with_values(TypeNameValue),
+///     )
+/// }
+/// ```
+///
+/// Render values with a closure:
+///
+/// ```
+/// use xpct::core::Matcher;
+/// use xpct::format::MismatchFormat;
+/// use xpct::matchers::equal::EqualMatcher;
+///
+/// pub fn equal_price<'a>(expected: u64) -> Matcher<'a, u64, u64> {
+///     Matcher::new(
+///         EqualMatcher::new(expected),
+///         MismatchFormat::new("to equal", "to not equal")
+///             .with_values(|cents: &u64| format!("${}.{:02}", cents / 100, cents % 100)),
+///     )
+/// }
+/// ```
+///
+/// [`MismatchFormat`]: crate::format::MismatchFormat
+/// [`ExpectationFormat`]: crate::format::ExpectationFormat
+/// [`MismatchFormat::with_values`]: crate::format::MismatchFormat::with_values
+/// [`ExpectationFormat::with_values`]: crate::format::ExpectationFormat::with_values
+/// [`Debug`]: std::fmt::Debug
+/// [`Display`]: std::fmt::Display
+/// [`equal`]: crate::equal
+pub trait ValueFormat<T: ?Sized> {
+    /// Render the value on a single line.
+    fn fmt_value(&self, value: &T) -> String;
+
+    /// Render the value across multiple lines.
+    ///
+    /// This is used when the formatter has room to show the value in full, like when showing a
+    /// diff. By default, this is the same as [`fmt_value`].
+    ///
+    /// [`fmt_value`]: crate::core::ValueFormat::fmt_value
+    fn fmt_value_pretty(&self, value: &T) -> String {
+        self.fmt_value(value)
+    }
+}
+
+impl<T, F> ValueFormat<T> for F
+where
+    T: ?Sized,
+    F: Fn(&T) -> String,
+{
+    fn fmt_value(&self, value: &T) -> String {
+        self(value)
+    }
+}
+
+/// A [`ValueFormat`] which renders values with their [`Debug`] implementation.
+///
+/// This renders values with `{:?}`, and uses `{:#?}` when there's room to show the value across
+/// multiple lines. This is the default for the provided formatters.
+///
+/// [`Debug`]: std::fmt::Debug
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DebugValue;
+
+impl<T: fmt::Debug + ?Sized> ValueFormat<T> for DebugValue {
+    fn fmt_value(&self, value: &T) -> String {
+        format!("{:?}", value)
+    }
+
+    fn fmt_value_pretty(&self, value: &T) -> String {
+        format!("{:#?}", value)
+    }
+}
+
+/// A [`ValueFormat`] which always renders values with the pretty-printed [`Debug`] format `{:#?}`.
+///
+/// This is useful for deeply nested structs, which are hard to read on a single line.
+///
+/// [`Debug`]: std::fmt::Debug
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrettyDebugValue;
+
+impl<T: fmt::Debug + ?Sized> ValueFormat<T> for PrettyDebugValue {
+    fn fmt_value(&self, value: &T) -> String {
+        format!("{:#?}", value)
+    }
+}
+
+/// A [`ValueFormat`] which renders values with their [`Display`] implementation.
+///
+/// [`Display`]: std::fmt::Display
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DisplayValue;
+
+impl<T: fmt::Display + ?Sized> ValueFormat<T> for DisplayValue {
+    fn fmt_value(&self, value: &T) -> String {
+        value.to_string()
+    }
+}
+
+/// A [`ValueFormat`] which renders bytes as hexadecimal.
+///
+/// This renders each byte as two lowercase hex digits separated by spaces, like `de ad be ef`. When
+/// there's room to show the value across multiple lines, this shows 16 bytes per line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HexValue;
+
+fn fmt_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl<T: AsRef<[u8]> + ?Sized> ValueFormat<T> for HexValue {
+    fn fmt_value(&self, value: &T) -> String {
+        fmt_hex(value.as_ref())
+    }
+
+    fn fmt_value_pretty(&self, value: &T) -> String {
+        value
+            .as_ref()
+            .chunks(HEX_BYTES_PER_LINE)
+            .map(fmt_hex)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A [`ValueFormat`] which renders only the name of the type of the value.
+///
+/// This works for any type, so you can use it for values which don't implement [`Debug`]. The
+/// values are rendered like `my_crate::Token { .. }`.
+///
+/// The provided matchers don't fall back to this for types which don't implement [`Debug`]; you
+/// need to pass it to the formatter in your own matcher function. See [`ValueFormat`] for an
+/// example.
+///
+/// [`Debug`]: std::fmt::Debug
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypeNameValue;
+
+impl<T: ?Sized> ValueFormat<T> for TypeNameValue {
+    fn fmt_value(&self, _: &T) -> String {
+        format!("{} {{ .. }}", type_name::<T>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DebugValue, DisplayValue, HexValue, PrettyDebugValue, TypeNameValue, ValueFormat};
+
+    struct Token;
+
+    #[test]
+    fn debug_value_uses_debug() {
+        assert_eq!(DebugValue.fmt_value(&Some("a")), r#"Some("a")"#);
+        assert_eq!(DebugValue.fmt_value_pretty(&Some(1)), "Some(\n    1,\n)");
+    }
+
+    #[test]
+    fn pretty_debug_value_uses_pretty_debug() {
+        assert_eq!(PrettyDebugValue.fmt_value(&Some(1)), "Some(\n    1,\n)");
+    }
+
+    #[test]
+    fn display_value_uses_display() {
+        assert_eq!(DisplayValue.fmt_value("a"), "a");
+    }
+
+    #[test]
+    fn hex_value_renders_bytes() {
+        assert_eq!(HexValue.fmt_value(&[0xde, 0xad, 0xbe, 0xef]), "de ad be ef");
+        assert_eq!(
+            HexValue.fmt_value_pretty(&[0u8; 20]),
+            "00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00\n00 00 00 00",
+        );
+    }
+
+    #[test]
+    fn type_name_value_renders_type_name() {
+        assert_eq!(
+            TypeNameValue.fmt_value(&Token),
+            "xpct::core::format::value::tests::Token { .. }",
+        );
+    }
+
+    #[test]
+    fn closures_render_values() {
+        let values = |n: &u32| format!("#{}", n);
+        assert_eq!(values.fmt_value(&1), "#1");
+        assert_eq!(values.fmt_value_pretty(&1), "#1");
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use super::adapter::{DynTransformMatchAdapter, MatchAdapter, NegTransformMatchAdapter};
+use super::adapter::{DynMatchAdapter, DynTransformMatchAdapter, NegTransformMatchAdapter};
 use super::wrap::MatchWrapper;
 use super::{FormattedFailure, MatchOutcome, MatcherFormat};
 
@@ -11,7 +11,7 @@ use super::{FormattedFailure, MatchOutcome, MatcherFormat};
 pub trait TransformMatch {
     /// The type of the "actual" value passed into the matcher by [`expect!`].
     ///
-    /// [`expect!`]: crate::expect
+    /// [`expect!`]: crate::expect!
     type In;
 
     /// The output type of the matcher that is passed to subsequent matchers in the chain.
@@ -96,6 +96,25 @@ pub trait Match<Actual> {
     /// [`expect!`]: crate::expect
     /// [`matches`]: crate::core::Match::matches
     fn fail(self, actual: Actual) -> Self::Fail;
+
+    /// Consumes the "actual" value and returns a [`Self::Fail`] that describes what the matcher
+    /// tested, or `None` if it can't describe itself.
+    ///
+    /// Combinator matchers in [verbose mode] call this to show what the matchers which succeeded
+    /// tested, and format the value the same way as the output of [`fail`]. This returns `None`
+    /// by default, in which case the matcher is shown without a description.
+    ///
+    /// This will only ever be called if the matcher succeeded.
+    ///
+    /// [verbose mode]: crate::Config::verbose
+    /// [`fail`]: crate::core::Match::fail
+    fn describe(self, actual: Actual) -> Option<Self::Fail>
+    where
+        Self: Sized,
+    {
+        let _ = actual;
+        None
+    }
 }
 
 /// An object-safe version of [`TransformMatch`].
@@ -128,6 +147,42 @@ pub trait DynTransformMatch {
         self: Box<Self>,
         actual: Self::In,
     ) -> crate::Result<MatchOutcome<Self::NegOut, FormattedFailure>>;
+
+    /// Same as [`match_pos`], except when the matcher succeeds, this returns a description of what
+    /// it tested instead of its output.
+    ///
+    /// Matchers created from a [`Match`] implementation are described by [`Match::describe`], which
+    /// returns `None` unless the matcher opts in. Other matchers always return `None`.
+    ///
+    /// Combinator matchers like [`each`] and [`any`] use this in [verbose mode] to show what the
+    /// matchers which succeeded were.
+    ///
+    /// [`match_pos`]: crate::core::DynTransformMatch::match_pos
+    /// [`each`]: crate::each
+    /// [`any`]: crate::any
+    /// [verbose mode]: crate::Config::verbose
+    fn describe_pos(
+        self: Box<Self>,
+        actual: Self::In,
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        Ok(match self.match_pos(actual)? {
+            MatchOutcome::Success(_) => MatchOutcome::Success(None),
+            MatchOutcome::Fail(fail) => MatchOutcome::Fail(fail),
+        })
+    }
+
+    /// Same as [`describe_pos`], but for when we're expecting the matcher to fail.
+    ///
+    /// [`describe_pos`]: crate::core::DynTransformMatch::describe_pos
+    fn describe_neg(
+        self: Box<Self>,
+        actual: Self::In,
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        Ok(match self.match_neg(actual)? {
+            MatchOutcome::Success(_) => MatchOutcome::Success(None),
+            MatchOutcome::Fail(fail) => MatchOutcome::Fail(fail),
+        })
+    }
 }
 
 /// A boxed [`DynTransformMatch`].
@@ -156,7 +211,9 @@ impl<'a, Actual> Matcher<'a, Actual, Actual> {
         Fmt: MatcherFormat<Pos = M::Fail, Neg = M::Fail> + 'a,
         Actual: 'a,
     {
-        Self::transform(MatchAdapter::new(matcher), format)
+        Self {
+            inner: Box::new(DynMatchAdapter::new(matcher, format, false)),
+        }
     }
 
     /// Same as [`new`], but negates the matcher.
@@ -172,7 +229,9 @@ impl<'a, Actual> Matcher<'a, Actual, Actual> {
         Fmt: MatcherFormat<Pos = M::Fail, Neg = M::Fail> + 'a,
         Actual: 'a,
     {
-        Self::transform_neg(MatchAdapter::new(matcher), format)
+        Self {
+            inner: Box::new(DynMatchAdapter::new(matcher, format, true)),
+        }
     }
 }
 
@@ -211,7 +270,9 @@ impl<'a, In, PosOut, NegOut> Matcher<'a, In, PosOut, NegOut> {
         NegOut: 'a,
         Fmt: MatcherFormat<Pos = FormattedFailure, Neg = FormattedFailure> + 'a,
     {
-        Self::transform(MatchWrapper::new(self), format)
+        Self {
+            inner: Box::new(MatchWrapper::new(self, format)),
+        }
     }
 
     /// Convert this matcher into a [`BoxTransformMatch`].
@@ -239,4 +300,18 @@ impl<'a, In, PosOut, NegOut> DynTransformMatch for Matcher<'a, In, PosOut, NegOu
     ) -> crate::Result<MatchOutcome<Self::NegOut, FormattedFailure>> {
         self.inner.match_neg(actual)
     }
+
+    fn describe_pos(
+        self: Box<Self>,
+        actual: Self::In,
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        self.inner.describe_pos(actual)
+    }
+
+    fn describe_neg(
+        self: Box<Self>,
+        actual: Self::In,
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        self.inner.describe_neg(actual)
+    }
 }
@@ -11,11 +11,14 @@ mod context;
 mod format;
 mod matcher;
 mod result;
+mod unwind;
 mod wrap;
 
-pub use assertion::Assertion;
-pub use context::{AssertionContext, FileLocation};
+pub use assertion::{expect, Assertion};
+pub use context::{context, current_context, AssertionContext, ContextGuard, FileLocation};
 pub use format::strings::whitespace;
 pub use format::*;
 pub use matcher::{BoxTransformMatch, DynTransformMatch, Match, Matcher, TransformMatch};
 pub use result::{AssertionFailure, FormattedFailure, MatchError, MatchFailure, MatchOutcome};
+
+pub(crate) use unwind::catch_panic;
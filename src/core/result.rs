@@ -122,9 +122,16 @@ pub struct AssertionFailure<Ctx> {
     ///
     /// [`DefaultAssertionFormat`]: crate::core::DefaultAssertionFormat
     /// [`AssertionContext`]: crate::core::AssertionContext
-    /// [`expect!`]: crate::expect
+    /// [`expect!`]: crate::expect!
     pub ctx: Ctx,
 
+    /// The descriptions of the [`context`] scopes the assertion failed in, outermost first.
+    ///
+    /// These are read when the assertion fails, so assertions that pass never pay for them.
+    ///
+    /// [`context`]: crate::context
+    pub context: Vec<String>,
+
     /// The error that caused this assertion to fail.
     pub error: MatchError,
 }
@@ -0,0 +1,80 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::ErrorKind;
+
+/// Get the message from the payload of a panic.
+///
+/// Panics created with `panic!` have either a `&'static str` or a `String` as their payload.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&'static str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// Call `func`, returning an error which reports the panic message if it panics.
+///
+/// This is used to call matchers and the closures passed to them, so that a panic in a closure is
+/// reported as a failed assertion with its expression and location instead of escaping on its own.
+///
+/// The panic hook still runs, so the original panic message and location are printed too.
+pub(crate) fn catch_panic<T>(func: impl FnOnce() -> T) -> crate::Result<T> {
+    // If `func` panics, we report the error and then panic again, so its captured state is never
+    // observed in a broken state.
+    panic::catch_unwind(AssertUnwindSafe(func)).map_err(|payload| {
+        crate::Error::msg(format!("panicked: {}", panic_message(payload.as_ref())))
+            .with_kind(ErrorKind::Panic)
+    })
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use super::catch_panic;
+    use crate::ErrorKind;
+    use crate::{be_gt, equal, every, expect, map};
+
+    #[test]
+    fn returns_value_when_no_panic() {
+        assert_eq!(catch_panic(|| 1 + 1).unwrap(), 2);
+    }
+
+    #[test]
+    fn reports_str_panic_message() {
+        let error = catch_panic(|| panic!("oh no")).unwrap_err();
+        assert_eq!(error.to_string(), "panicked: oh no");
+        assert_eq!(error.kind(), ErrorKind::Panic);
+    }
+
+    #[test]
+    fn reports_formatted_panic_message() {
+        let value = 5;
+        let error = catch_panic(|| panic!("bad value: {}", value)).unwrap_err();
+        assert_eq!(error.to_string(), "panicked: bad value: 5");
+    }
+
+    #[test]
+    #[should_panic(expected = "panicked: boom")]
+    fn catches_panic_in_map_method() {
+        expect!(1).map(|_: i32| -> i32 { panic!("boom") });
+    }
+
+    #[test]
+    #[should_panic(expected = "panicked: boom")]
+    fn catches_panic_in_matcher_closure() {
+        expect!(1)
+            .to(map(|_: i32| -> i32 { panic!("boom") }))
+            .to(equal(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "panicked: boom")]
+    fn catches_panic_in_lazy_iter_map() {
+        expect!([2, 1])
+            .iter_map(|_| -> i32 { panic!("boom") })
+            .to(every(|| be_gt(0)));
+    }
+}
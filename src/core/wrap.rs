@@ -1,45 +1,108 @@
 use std::fmt;
 
-use super::{matcher::TransformMatch, BoxTransformMatch, FormattedFailure, MatchOutcome, Matcher};
+use super::{
+    BoxTransformMatch, DynTransformMatch, FormattedFailure, MatchFailure, MatchOutcome, Matcher,
+    MatcherFormat,
+};
 
-pub(super) struct MatchWrapper<'a, In, PosOut, NegOut> {
+pub(super) struct MatchWrapper<'a, In, PosOut, NegOut, Fmt> {
     inner: BoxTransformMatch<'a, In, PosOut, NegOut>,
+    format: Fmt,
 }
 
-impl<'a, In, PosOut, NegOut> MatchWrapper<'a, In, PosOut, NegOut> {
-    pub fn new(matcher: Matcher<'a, In, PosOut, NegOut>) -> Self {
+impl<'a, In, PosOut, NegOut, Fmt> MatchWrapper<'a, In, PosOut, NegOut, Fmt> {
+    pub fn new(matcher: Matcher<'a, In, PosOut, NegOut>, format: Fmt) -> Self {
         Self {
             inner: matcher.into_box(),
+            format,
         }
     }
 }
 
-impl<'a, In, PosOut, NegOut> fmt::Debug for MatchWrapper<'a, In, PosOut, NegOut> {
+impl<'a, In, PosOut, NegOut, Fmt> fmt::Debug for MatchWrapper<'a, In, PosOut, NegOut, Fmt>
+where
+    Fmt: fmt::Debug,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("MatchWrapper").finish_non_exhaustive()
+        f.debug_struct("MatchWrapper")
+            .field("format", &self.format)
+            .finish_non_exhaustive()
     }
 }
 
-impl<'a, In, PosOut, NegOut> TransformMatch for MatchWrapper<'a, In, PosOut, NegOut> {
+// Wrap the description of the inner matcher with the formatter too, so that wrapping a matcher with
+// a formatter like `WhyFormat` doesn't lose it.
+fn wrap_outcome<Fmt>(
+    outcome: MatchOutcome<Option<FormattedFailure>, FormattedFailure>,
+    format: Fmt,
+    pos: bool,
+) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>>
+where
+    Fmt: MatcherFormat<Pos = FormattedFailure, Neg = FormattedFailure>,
+{
+    let wrap = |output| {
+        if pos {
+            FormattedFailure::new(MatchFailure::Pos(output), format)
+        } else {
+            FormattedFailure::new(MatchFailure::Neg(output), format)
+        }
+    };
+
+    Ok(match outcome {
+        MatchOutcome::Success(Some(description)) => MatchOutcome::Success(Some(wrap(description)?)),
+        MatchOutcome::Success(None) => MatchOutcome::Success(None),
+        MatchOutcome::Fail(fail) => MatchOutcome::Fail(wrap(fail)?),
+    })
+}
+
+impl<'a, In, PosOut, NegOut, Fmt> DynTransformMatch for MatchWrapper<'a, In, PosOut, NegOut, Fmt>
+where
+    Fmt: MatcherFormat<Pos = FormattedFailure, Neg = FormattedFailure>,
+{
     type In = In;
 
     type PosOut = PosOut;
     type NegOut = NegOut;
 
-    type PosFail = FormattedFailure;
-    type NegFail = FormattedFailure;
-
     fn match_pos(
-        self,
+        self: Box<Self>,
         actual: Self::In,
-    ) -> crate::Result<MatchOutcome<Self::PosOut, Self::PosFail>> {
-        self.inner.match_pos(actual)
+    ) -> crate::Result<MatchOutcome<Self::PosOut, FormattedFailure>> {
+        match self.inner.match_pos(actual)? {
+            MatchOutcome::Success(out) => Ok(MatchOutcome::Success(out)),
+            MatchOutcome::Fail(fail) => Ok(MatchOutcome::Fail(FormattedFailure::new(
+                MatchFailure::Pos(fail),
+                self.format,
+            )?)),
+        }
     }
 
     fn match_neg(
-        self,
+        self: Box<Self>,
+        actual: Self::In,
+    ) -> crate::Result<MatchOutcome<Self::NegOut, FormattedFailure>> {
+        match self.inner.match_neg(actual)? {
+            MatchOutcome::Success(out) => Ok(MatchOutcome::Success(out)),
+            MatchOutcome::Fail(fail) => Ok(MatchOutcome::Fail(FormattedFailure::new(
+                MatchFailure::Neg(fail),
+                self.format,
+            )?)),
+        }
+    }
+
+    fn describe_pos(
+        self: Box<Self>,
+        actual: Self::In,
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        let Self { inner, format } = *self;
+        wrap_outcome(inner.describe_pos(actual)?, format, true)
+    }
+
+    fn describe_neg(
+        self: Box<Self>,
         actual: Self::In,
-    ) -> crate::Result<MatchOutcome<Self::NegOut, Self::NegFail>> {
-        self.inner.match_neg(actual)
+    ) -> crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>> {
+        let Self { inner, format } = *self;
+        wrap_outcome(inner.describe_neg(actual)?, format, false)
     }
 }
@@ -11,7 +11,35 @@ Enables the [`match_regex`] matcher, which requires additional dependencies.
 
 ## `json`
 
-Enables the [`match_json`] matcher, which requires additional dependencies.
+Enables the [`match_json`], [`serialize_to_json`], [`deserialize_from_json`],
+and [`roundtrip_json`] matchers, which require additional dependencies.
+
+This also allows you to use [`eq_diff`] with [`serde_json::Value`]s.
+
+This implies `diff`.
+
+## `json-schema`
+
+Enables the [`match_json_schema`] matcher, which requires additional
+dependencies.
+
+This implies `json`.
+
+## `yaml`
+
+Enables the [`match_yaml`] matcher, which requires additional dependencies.
+
+This also allows you to use [`eq_diff`] with [`serde_yaml::Value`]s.
+
+This implies `diff`.
+
+## `toml`
+
+Enables the [`match_toml`] matcher, which requires additional dependencies.
+
+This also allows you to use [`eq_diff`] with [`toml::Value`]s.
+
+This implies `diff`.
 
 ## `float`
 
@@ -24,7 +52,8 @@ Enables the [`eq_casefold`] matcher, which requires additional dependencies.
 
 ## `diff`
 
-Enables the [`eq_diff`] matcher, which requires additional dependencies.
+Enables the [`eq_diff`], [`eq_diff_with`], [`eq_debug_diff`], and [`eq_bytes`]
+matchers, which require additional dependencies.
 
 ## `unicode-diff`
 
@@ -33,6 +62,13 @@ character, which requires additional dependencies.
 
 This implies `diff`.
 
+## `derive`
+
+Enables `#[derive(Diffable)]`, which lets you use [`eq_diff`] with your own
+structs and enums. See [`Diffable`] for details.
+
+This implies `diff`.
+
 ## `color` *(default)*
 
 Enable colors and text styles in the output. This is enabled by default.
@@ -43,12 +79,18 @@ means that [`Formatter::set_style`] still exists, but is basically a no-op.
 
 Even with this feature enabled, text colors and styles are disabled when stderr
 is not a tty or when the [`NO_COLOR`](https://no-color.org/) environment variable
-is set.
+is set, unless the [`CLICOLOR_FORCE`](https://bixense.com/clicolors/)
+environment variable is set. You can override this with [`set_color_choice`],
+and change the colors with [`set_theme`].
 
 ## `fmt` *(default)*
 
 Enable the provided formatters. This is enabled by default.
 
+This also lets xpct query the width of the terminal, which requires an
+additional dependency. Without it, the width of the terminal is only read from
+the `COLUMNS` environment variable.
+
 You do not want to disable this feature unless you want reimplement **all** the
 provided formatters yourself, which also includes the matcher functions
 ([`equal`], [`be_some`], etc.), since they rely on these formatters. What this
@@ -60,10 +102,22 @@ disabled, the flags for it are hidden in docs.rs to reduce noise.
 
 [`match_regex`]: crate::match_regex
 [`match_json`]: crate::match_json
+[`serialize_to_json`]: crate::serialize_to_json
+[`deserialize_from_json`]: crate::deserialize_from_json
+[`roundtrip_json`]: crate::roundtrip_json
+[`match_json_schema`]: crate::match_json_schema
+[`match_yaml`]: crate::match_yaml
+[`match_toml`]: crate::match_toml
 [`approx_eq_f32`]: crate::approx_eq_f32
 [`approx_eq_f64`]: crate::approx_eq_f64
 [`eq_casefold`]: crate::eq_casefold
 [`eq_diff`]: crate::eq_diff
+[`Diffable`]: crate::matchers::diff::Diffable
+[`eq_diff_with`]: crate::eq_diff_with
+[`eq_debug_diff`]: crate::eq_debug_diff
+[`eq_bytes`]: crate::eq_bytes
+[`set_color_choice`]: crate::core::set_color_choice
+[`set_theme`]: crate::core::set_theme
 [`equal`]: crate::equal
 [`be_some`]: crate::be_some
 [`Formatter::set_style`]: crate::core::Formatter::set_style
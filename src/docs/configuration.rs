@@ -0,0 +1,174 @@
+/*!
+# Configuration
+
+How to change the output of the provided formatters for a whole test suite,
+including translating it into other languages.
+
+[↩︎ Back to User Docs](crate::docs)
+
+## Global settings
+
+The provided formatters read their layout and limits from a global [`Config`].
+You can read the current settings with [`config`] and change them with
+[`update_config`] or [`set_config`]:
+
+```
+use xpct::core::ColorChoice;
+
+xpct::update_config(|config| {
+    config.indent = 2;
+    config.ok_symbol = String::from("✓");
+    config.failed_symbol = String::from("✗");
+    config.max_value_len = Some(1024);
+    config.diff_context = Some(5);
+    config.color = ColorChoice::Never;
+});
+```
+
+These settings affect all threads, so it's best to change them once, before
+any assertions run, rather than from individual tests that may run in parallel.
+
+## Environment variables
+
+Each setting can also be set with an `XPCT_*` environment variable, which is
+useful for changing the output in CI without changing any code:
+
+```text
+XPCT_COLOR=always XPCT_MAX_VALUE_LEN=none cargo test
+```
+
+The environment variables are read once, the first time the settings are
+used. Settings changed in code take precedence over them. See [`Config`] for
+the full list of environment variables and the values they accept.
+
+## Source snippets
+
+Set [`Config::source_snippet`] or the `XPCT_SOURCE_SNIPPET` environment
+variable to print the lines of source code around each failing assertion, with
+a caret under the column where it was made:
+
+```text
+[src/lib.rs:5:5] = value
+  |
+4 |     let value = 1;
+5 |     expect!(value).to(equal(2));
+  |     ^
+6 | }
+```
+
+If the source file can't be found, like when its path has been remapped, the
+snippet is left out.
+
+## Compact output
+
+When running many table-driven test cases in CI, it can be easier to read one
+line per failure. The [`CompactAssertionFormat`] prints the location, the
+expression, and the failure message on a single line:
+
+```text
+src/lib.rs:42: parse_ratio(input): Expected: Ok(1.5) to equal: Ok(2.0)
+```
+
+You can use it for individual assertions with [`expect_with!`], or for every
+assertion made with [`expect!`] by setting [`Config::compact`] or the
+`XPCT_COMPACT=1` environment variable.
+
+## Verbose output
+
+By default, combinator matchers like [`any`] and [`each`] only print the
+matchers which failed. When debugging a flaky test, it can help to see which of
+them passed too. Setting [`Config::verbose`] or the `XPCT_VERBOSE=1`
+environment variable makes them print every matcher they compose, marking each
+one as OK or FAILED:
+
+```text
+Expected all of these to succeed:
+    [0]  OK
+    [1]  FAILED
+         Expected:
+             5
+         to be less than:
+             3
+
+```
+
+To turn this on for a single assertion, use [`each_verbose`], [`any_verbose`],
+or [`all_verbose`] in place of [`each`], [`any`], or [`all`].
+
+The matchers which succeeded are shown as just OK, unless they can describe
+what they tested. None of the provided matchers do, but your own matchers can
+opt in by implementing [`Match::describe`]. Because [`all`] passes the output of
+each matcher to the next, none of its matchers can describe themselves; in
+verbose mode, it marks the matchers before the one which failed as OK so you can
+see how far it got. [`match_fields`] always prints every field.
+
+If you're writing your own combinator matcher, see
+[`DynTransformMatch::describe_pos`] and [`SomeOutcomesFormat`].
+
+## Translating messages
+
+Every message in the output of the provided matchers and formatters has a
+stable ID, like `equal.pos` for the "to equal" message of [`equal`]. You can
+translate the output by installing a [`Catalog`] of messages with
+[`set_catalog`]. Any messages missing from your catalog fall back to the
+[`EnglishCatalog`], which lists every message ID.
+
+```
+use std::collections::HashMap;
+
+use xpct::core::set_catalog;
+
+set_catalog(HashMap::from([
+    (String::from("equal.pos"), String::from("gleich zu sein")),
+    (String::from("equal.neg"), String::from("nicht gleich zu sein")),
+    (String::from("diff.unchanged-lines"), String::from("… {count} unveränderte Zeilen …")),
+    (String::from("diff.unchanged-lines.one"), String::from("… {count} unveränderte Zeile …")),
+]));
+```
+
+Your own matchers can look up messages with [`message`] and [`message_with`]
+too, using IDs of your choosing. To use a message ID with a provided formatter
+like [`MismatchFormat`], pass it a [`Message::id`], which is looked up each time
+a failure is formatted. Messages which no catalog has are printed as their ID.
+
+The `{count}` parameter is formatted by the installed catalog with
+[`Catalog::format_count`], so you can override that to use your language's
+digit separators.
+
+## Colors
+
+The colors and text styles used by the provided formatters are configured
+separately with [`set_theme`]. See [Writing Custom
+Formatters](crate::docs::writing_formatters) for information.
+
+[`MismatchFormat`]: crate::format::MismatchFormat
+[`Message::id`]: crate::core::Message::id
+[`Catalog::format_count`]: crate::core::Catalog::format_count
+[`Config`]: crate::Config
+[`config`]: crate::config
+[`update_config`]: crate::update_config
+[`set_config`]: crate::set_config
+[`set_theme`]: crate::core::set_theme
+[`equal`]: crate::equal
+[`CompactAssertionFormat`]: crate::core::CompactAssertionFormat
+[`expect_with!`]: crate::expect_with
+[`expect!`]: crate::expect!
+[`Config::compact`]: crate::Config::compact
+[`Config::source_snippet`]: crate::Config::source_snippet
+[`Config::verbose`]: crate::Config::verbose
+[`any`]: crate::any
+[`each`]: crate::each
+[`all`]: crate::all
+[`match_fields`]: crate::match_fields
+[`each_verbose`]: crate::each_verbose
+[`any_verbose`]: crate::any_verbose
+[`all_verbose`]: crate::all_verbose
+[`Match::describe`]: crate::core::Match::describe
+[`DynTransformMatch::describe_pos`]: crate::core::DynTransformMatch::describe_pos
+[`SomeOutcomesFormat`]: crate::format::SomeOutcomesFormat
+[`Catalog`]: crate::core::Catalog
+[`set_catalog`]: crate::core::set_catalog
+[`EnglishCatalog`]: crate::core::EnglishCatalog
+[`message`]: crate::core::message
+[`message_with`]: crate::core::message_with
+*/
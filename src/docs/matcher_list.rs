@@ -9,6 +9,9 @@ A list of all the matchers provided by this crate, grouped into categories.
 
 - [`equal`][crate::equal]
 - [`eq_diff`][crate::eq_diff]
+- [`eq_diff_with`][crate::eq_diff_with]
+- [`eq_debug_diff`][crate::eq_debug_diff]
+- [`eq_bytes`][crate::eq_bytes]
 
 ## Negating
 
@@ -79,6 +82,9 @@ A list of all the matchers provided by this crate, grouped into categories.
 - [`have_suffix`][crate::have_suffix]
 - [`match_regex`][crate::match_regex]
 - [`match_json`][crate::match_json]
+- [`match_json_schema`][crate::match_json_schema]
+- [`match_yaml`][crate::match_yaml]
+- [`match_toml`][crate::match_toml]
 - [`eq_casefold`][crate::eq_casefold]
 
 ## Numbers
@@ -108,4 +114,10 @@ A list of all the matchers provided by this crate, grouped into categories.
 - [`be_regular_file`][crate::be_regular_file]
 - [`be_directory`][crate::be_directory]
 - [`be_symlink`][crate::be_symlink]
+
+## Serialization
+
+- [`serialize_to_json`][crate::serialize_to_json]
+- [`deserialize_from_json`][crate::deserialize_from_json]
+- [`roundtrip_json`][crate::roundtrip_json]
 */
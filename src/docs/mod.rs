@@ -8,6 +8,7 @@ This is the xpct user documentation. If you're new here, check out the
 #![cfg(docsrs)]
 
 pub mod cargo_features;
+pub mod configuration;
 pub mod matcher_list;
 pub mod tutorial;
 pub mod writing_formatters;
@@ -159,6 +159,23 @@ expect!("Kim").to(why(
 ));
 ```
 
+If you want to add context to every assertion in a block of code, like in a
+helper function which is called for many different values, you can use
+[`context`]. Failures inside it print the descriptions of all the enclosing
+contexts above the matcher output:
+
+```
+use xpct::{be_gt, expect};
+
+let totals = [10, 20, 30];
+
+xpct::context("validating order 42", || {
+    for total in totals {
+        expect!(total).to(be_gt(0));
+    }
+});
+```
+
 You can test that a value matches a pattern using the [`match_pattern`] matcher
 and the [`pattern!`] macro.
 
@@ -281,6 +298,26 @@ The matchers for collections are implemented using the [`Len`] and [`Contains`]
 traits. You can implement these traits for your own types to use them with the
 collections matchers.
 
+If you share assertions between tests using helper functions, use the [`expect`]
+function instead of the [`expect!`] macro and mark the helper with
+`#[track_caller]`. That way, failures point to the line in the test which called
+the helper rather than the line inside the helper.
+
+```
+use xpct::{be_ge, expect};
+
+struct User {
+    age: u32,
+}
+
+#[track_caller]
+fn assert_adult(user: &User) {
+    expect(user.age).to(be_ge(18));
+}
+
+assert_adult(&User { age: 30 });
+```
+
 Check out [Provided Matchers][crate::docs::matcher_list] for a list of all the
 matchers provided by this crate.
 
@@ -298,11 +335,13 @@ matchers provided by this crate.
 [`any`]: crate::any
 [`why`]: crate::why
 [`why_lazy`]: crate::why_lazy
+[`context`]: crate::context
 [`match_pattern`]: crate::match_pattern
 [`Diffable`]: crate::matchers::diff::Diffable
 [`pattern!`]: crate::pattern
 [`match_fields`]: crate::match_fields
-[`expect!`]: crate::expect
+[`expect!`]: crate::expect!
+[`expect`]: crate::expect()
 [`fields!`]: crate::fields
 [`contain_element`]: crate::contain_element
 [`contain_elements`]: crate::contain_elements
@@ -88,21 +88,70 @@ where
 }
 ```
 
+## Changing how values are rendered
+
+If you only want to change how values are rendered, you don't need to write a
+whole formatter. The provided formatters like [`MismatchFormat`] render values
+with their [`Debug`] implementation by default, but you can pass a
+[`ValueFormat`] to [`MismatchFormat::with_values`] to render them differently.
+
+There's no way to register a [`ValueFormat`] for a type globally, and no way to
+change how the provided matchers like [`equal`] render values. They always use
+[`Debug`], and they require the values to implement it; values of types which
+don't implement [`Debug`] can't be used with them at all. To render values
+differently, or to make assertions on types without [`Debug`], write your own
+matcher function which uses a [`ValueFormat`], like in the example below.
+
+There are provided implementations for rendering values with [`Display`]
+([`DisplayValue`]), as hexadecimal bytes ([`HexValue`]), with the pretty-printed
+`{:#?}` format ([`PrettyDebugValue`]), or by their type name for types that
+don't implement [`Debug`] ([`TypeNameValue`]). You can also pass a closure which
+accepts a reference to the value and returns a [`String`].
+
+```
+use xpct::core::{HexValue, Matcher};
+use xpct::format::MismatchFormat;
+use xpct::matchers::equal::EqualMatcher;
+
+pub fn equal_bytes<'a>(expected: Vec<u8>) -> Matcher<'a, Vec<u8>, Vec<u8>> {
+    Matcher::new(
+        EqualMatcher::new(expected),
+        MismatchFormat::new("to equal", "to not equal").with_values(HexValue),
+    )
+}
+```
+
 ## Colors and text styles
 
 Formatters also support styling the output with colors and text styles using the
 [`Formatter::set_style`] and [`Formatter::reset_style`] methods.
 
-Colors and text styles are never emitted when stderr is not a tty or when the
-[`NO_COLOR`](https://no-color.org/) environment variable is set. You can also
-remove support for colors and text styles by disabling the default `color` Cargo
-feature. See [Cargo Features][crate::docs::cargo_features] for information.
+By default, colors and text styles are not emitted when stderr is not a tty or
+when the [`NO_COLOR`](https://no-color.org/) environment variable is set, unless
+the [`CLICOLOR_FORCE`](https://bixense.com/clicolors/) environment variable is
+set. You can override this with [`set_color_choice`] or the `XPCT_COLOR`
+environment variable; see [Configuration][crate::docs::configuration]. You can
+also remove support for colors and text styles by disabling the default `color`
+Cargo feature. See [Cargo Features][crate::docs::cargo_features] for information.
+
+The colors used by the provided formatters come from a [`Theme`], which you can
+change with [`set_theme`]. There's a provided [`Theme::colorblind`] palette
+which uses blue and yellow instead of green and red. If your formatter uses its
+own colors, consider deriving them from the current [`theme`] too.
 
 Colors and text styles can be useful to make the output easier to read, but they
 should not convey any information that's not already in the text. Some
 developers don't experience color the same way you do, some developers use
 screen readers, and some developers just prefer to have colors disabled.
 
+## Rendering to HTML or Markdown
+
+Formatted output is shown in the terminal by default, but you can also render a
+[`FormattedOutput`] to other formats using [`FormattedOutput::render`], like
+for a test report or a comment on a pull request. There are provided
+[`HtmlRenderer`] and [`MarkdownRenderer`] backends, and you can implement the
+[`Render`] trait to write your own.
+
 ## Composing formatters
 
 If your matcher composes other matchers, it will likely pass a
@@ -122,4 +171,22 @@ through to your formatter's output.
 [`Formatter::set_style`]: crate::core::Formatter::set_style
 [`Formatter::reset_style`]: crate::core::Formatter::reset_style
 [`FormattedFailure`]: crate::core::FormattedFailure
+[`MismatchFormat::with_values`]: crate::format::MismatchFormat::with_values
+[`ValueFormat`]: crate::core::ValueFormat
+[`DisplayValue`]: crate::core::DisplayValue
+[`HexValue`]: crate::core::HexValue
+[`PrettyDebugValue`]: crate::core::PrettyDebugValue
+[`TypeNameValue`]: crate::core::TypeNameValue
+[`Debug`]: std::fmt::Debug
+[`Display`]: std::fmt::Display
+[`set_color_choice`]: crate::core::set_color_choice
+[`Theme`]: crate::core::Theme
+[`Theme::colorblind`]: crate::core::Theme::colorblind
+[`set_theme`]: crate::core::set_theme
+[`theme`]: crate::core::theme
+[`FormattedOutput`]: crate::core::FormattedOutput
+[`FormattedOutput::render`]: crate::core::FormattedOutput::render
+[`HtmlRenderer`]: crate::core::HtmlRenderer
+[`MarkdownRenderer`]: crate::core::MarkdownRenderer
+[`Render`]: crate::core::Render
 */
@@ -250,6 +250,46 @@ where
 }
 ```
 
+## Returning errors
+
+Matchers return a [`Result`], so they can return an [`Error`] when they can't
+make a decision at all, like when a regex is malformed or a file can't be read.
+This is printed as a "matcher error" rather than as a failed assertion, along
+with the [`ErrorKind`] of the error, its chain of sources, and a backtrace if
+`RUST_BACKTRACE` is set.
+
+The kind is guessed from the type of the error you wrap, but you can set it with
+[`Error::with_kind`]. You can add notes explaining what the matcher was doing
+with [`Error::context`]:
+
+```
+use std::fs;
+
+use xpct::{Error, ErrorKind};
+
+fn read_fixture(path: &str) -> xpct::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|error| Error::new(error).context(format!("failed to read {}", path)))
+}
+
+fn parse_port(value: &str) -> xpct::Result<u16> {
+    value
+        .parse()
+        .map_err(|error| Error::new(error).with_kind(ErrorKind::Input))
+}
+
+let error = read_fixture("does/not/exist.json").unwrap_err();
+
+assert_eq!(error.kind(), ErrorKind::Io);
+assert_eq!(error.to_string(), "failed to read does/not/exist.json");
+assert_eq!(parse_port("http").unwrap_err().kind(), ErrorKind::Input);
+```
+
+[`Result`]: crate::Result
+[`Error`]: crate::Error
+[`ErrorKind`]: crate::ErrorKind
+[`Error::with_kind`]: crate::Error::with_kind
+[`Error::context`]: crate::Error::context
 [`TransformMatch`]: crate::core::TransformMatch
 [`each`]: crate::each
 [`any`]: crate::any
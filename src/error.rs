@@ -1,23 +1,96 @@
+use std::any::Any;
+use std::backtrace::Backtrace;
 use std::error::Error as StdError;
 use std::fmt;
+use std::io;
 use std::ops::Deref;
 use std::result;
 
+/// The category of an [`Error`].
+///
+/// This is used to tell apart errors caused by the values passed to a matcher from errors caused
+/// by the environment, like a file that can't be read.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The matcher was given invalid input, like a malformed regex or invalid JSON.
+    Input,
+
+    /// An I/O operation failed.
+    Io,
+
+    /// The matcher or a closure passed to it panicked.
+    Panic,
+
+    /// Any other error.
+    Other,
+}
+
+impl ErrorKind {
+    /// Guess the category of an error from its type.
+    fn of<E>(error: &E) -> Self
+    where
+        E: StdError + 'static,
+    {
+        let error = error as &dyn Any;
+
+        if error.is::<io::Error>() {
+            return Self::Io;
+        }
+
+        #[cfg(feature = "regex")]
+        if error.is::<regex::Error>() {
+            return Self::Input;
+        }
+
+        #[cfg(feature = "json")]
+        if error.is::<serde_json::Error>() {
+            return Self::Input;
+        }
+
+        #[cfg(feature = "yaml")]
+        if error.is::<serde_yaml::Error>() {
+            return Self::Input;
+        }
+
+        #[cfg(feature = "toml")]
+        if error.is::<toml::de::Error>() {
+            return Self::Input;
+        }
+
+        Self::Other
+    }
+}
+
 /// An error that occurs in a matcher.
 ///
-/// This wraps a dynamic error type.
+/// This wraps a dynamic error type, along with the [`ErrorKind`] of the error, any notes added
+/// with [`context`], and a backtrace.
+///
+/// The backtrace is only captured when the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment
+/// variables are set.
+///
+/// [`context`]: crate::Error::context
 #[derive(Debug)]
 pub struct Error {
     inner: anyhow::Error,
+    kind: ErrorKind,
 }
 
 impl Error {
     /// Construct a new `Error` that wraps the given `error`.
+    ///
+    /// The [`ErrorKind`] is guessed from the type of `error`. I/O errors are [`ErrorKind::Io`],
+    /// errors from parsing regexes, JSON, YAML, and TOML are [`ErrorKind::Input`], and everything
+    /// else is [`ErrorKind::Other`]. You can change it with [`with_kind`].
+    ///
+    /// [`with_kind`]: crate::Error::with_kind
     pub fn new<E>(error: E) -> Self
     where
         E: StdError + Send + Sync + 'static,
     {
         Self {
+            kind: ErrorKind::of(&error),
             inner: anyhow::Error::new(error),
         }
     }
@@ -33,8 +106,57 @@ impl Error {
     {
         Self {
             inner: anyhow::Error::msg(message),
+            kind: ErrorKind::Other,
+        }
+    }
+
+    /// Return this error with the given [`ErrorKind`].
+    pub fn with_kind(mut self, kind: ErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// The category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Wrap this error with a note explaining what was happening when it occurred.
+    ///
+    /// The note becomes the message of the error, and the original error becomes its source. The
+    /// [`ErrorKind`] of the error is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xpct::Error;
+    ///
+    /// let error = Error::msg("file not found").context("failed to load the fixture");
+    ///
+    /// assert_eq!(error.to_string(), "failed to load the fixture");
+    /// assert_eq!(format!("{:#}", error), "failed to load the fixture: file not found");
+    /// ```
+    pub fn context<C>(self, context: C) -> Self
+    where
+        C: fmt::Display + Send + Sync + 'static,
+    {
+        Self {
+            inner: self.inner.context(context),
+            kind: self.kind,
         }
     }
+
+    /// Iterate over this error and its chain of sources, starting with this error.
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn StdError + 'static)> {
+        self.inner.chain()
+    }
+
+    /// The backtrace of where this error was created.
+    ///
+    /// See [`Backtrace`] for when a backtrace is captured.
+    pub fn backtrace(&self) -> &Backtrace {
+        self.inner.backtrace()
+    }
 }
 
 impl<E> From<E> for Error
@@ -68,9 +190,50 @@ impl Deref for Error {
 
 impl From<Error> for Box<dyn StdError + Send + Sync + 'static> {
     fn from(error: Error) -> Self {
-        error.into()
+        error.inner.into()
     }
 }
 
 /// A result type for [`Error`].
 pub type Result<T> = result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::{Error, ErrorKind};
+
+    #[test]
+    fn io_errors_have_io_kind() {
+        let error = Error::new(io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(error.kind(), ErrorKind::Io);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn parse_errors_have_input_kind() {
+        let error = Error::new(serde_json::from_str::<serde_json::Value>("{").unwrap_err());
+        assert_eq!(error.kind(), ErrorKind::Input);
+    }
+
+    #[test]
+    fn messages_have_other_kind() {
+        assert_eq!(Error::msg("oops").kind(), ErrorKind::Other);
+        assert_eq!(
+            Error::msg("oops").with_kind(ErrorKind::Input).kind(),
+            ErrorKind::Input
+        );
+    }
+
+    #[test]
+    fn context_keeps_kind_and_source() {
+        let error = Error::new(io::Error::new(io::ErrorKind::Other, "disk on fire"))
+            .context("failed to load the fixture");
+
+        assert_eq!(error.kind(), ErrorKind::Io);
+        assert_eq!(
+            error.chain().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["failed to load the fixture", "disk on fire"],
+        );
+    }
+}
@@ -1,8 +1,73 @@
-use crate::core::style::AT_LESAT_ONE_NOT_OK_MSG;
-use crate::core::{DispatchFormat, MatchError, Matcher};
+use crate::core::{
+    style, DispatchFormat, Format, FormattedOutput, Formatter, MatchError, MatchFailure,
+    MatchOutcome, Matcher,
+};
 use crate::matchers::combinators::{ChainAssertion, ChainMatcher};
+use crate::matchers::SomeOutcomes;
 
-use super::{FailureFormat, MessageFormat};
+use super::{MessageFormat, SomeOutcomesFormat};
+
+/// A formatter for the [`SomeOutcomes`] values returned by [`all`].
+///
+/// By default, this only prints the output of the matcher which failed, the same as
+/// [`FailureFormat`]. In verbose mode, it also prints the matchers before it and marks them as
+/// `OK`, so you can see how far the chain got. Because [`all`] passes the output of each matcher to
+/// the next, the matchers which succeeded have no description.
+///
+/// Verbose mode is enabled globally with [`Config::verbose`], or for a single formatter with
+/// [`verbose`].
+///
+/// [`SomeOutcomes`]: crate::matchers::SomeOutcomes
+/// [`FailureFormat`]: crate::format::FailureFormat
+/// [`Config::verbose`]: crate::core::Config::verbose
+/// [`verbose`]: crate::format::ChainFormat::verbose
+#[non_exhaustive]
+#[derive(Debug, Default)]
+pub struct ChainFormat {
+    verbose: Option<bool>,
+}
+
+impl ChainFormat {
+    /// Create a new [`ChainFormat`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to also print the matchers which succeeded.
+    ///
+    /// This overrides [`Config::verbose`] for this formatter.
+    ///
+    /// [`Config::verbose`]: crate::core::Config::verbose
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
+    }
+}
+
+impl Format for ChainFormat {
+    type Value = MatchFailure<SomeOutcomes>;
+
+    fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
+        let outcomes = value.into_inner();
+
+        if self.verbose.unwrap_or_else(style::verbose) {
+            f.write_fmt(FormattedOutput::new(
+                outcomes,
+                SomeOutcomesFormat::new().verbose(true),
+            )?);
+
+            return Ok(());
+        }
+
+        for outcome in outcomes {
+            if let MatchOutcome::Fail(fail) = outcome {
+                f.write_fmt(fail);
+            }
+        }
+
+        Ok(())
+    }
+}
 
 /// Succeeds when all of the passed matchers succeed.
 ///
@@ -65,7 +130,7 @@ use super::{FailureFormat, MessageFormat};
 /// [`to`]: crate::matchers::combinators::ChainAssertion::to
 /// [`to_not`]: crate::matchers::combinators::ChainAssertion::to_not
 /// [`not`]: crate::not
-/// [`expect!`]: crate::expect
+/// [`expect!`]: crate::expect!
 /// [`match_fields`]: crate::match_fields
 pub fn all<'a, In, Out>(
     block: impl FnOnce(ChainAssertion<In>) -> Result<ChainAssertion<Out>, MatchError> + 'a,
@@ -75,8 +140,41 @@ where
     Out: 'a,
 {
     let format = DispatchFormat::new(
-        FailureFormat::new(),
-        MessageFormat::new("", AT_LESAT_ONE_NOT_OK_MSG),
+        ChainFormat::new(),
+        MessageFormat::new("", style::any_not_ok_msg()),
+    );
+
+    Matcher::transform(ChainMatcher::new(block), format)
+}
+
+/// Same as [`all`], but always in verbose mode.
+///
+/// When this matcher fails, it prints the matchers before the one which failed and marks them as
+/// OK, so you can see how far the chain got. This does the same thing as enabling
+/// [`Config::verbose`], but only for this assertion.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{all_verbose, be_some, equal, expect};
+///
+/// expect!(Some("horrific")).to(all_verbose(|ctx| ctx
+///     .to(be_some())?
+///     .to(equal("horrific"))
+/// ));
+/// ```
+///
+/// [`Config::verbose`]: crate::Config::verbose
+pub fn all_verbose<'a, In, Out>(
+    block: impl FnOnce(ChainAssertion<In>) -> Result<ChainAssertion<Out>, MatchError> + 'a,
+) -> Matcher<'a, In, Out, ()>
+where
+    In: 'a,
+    Out: 'a,
+{
+    let format = DispatchFormat::new(
+        ChainFormat::new().verbose(true),
+        MessageFormat::new("", style::any_not_ok_msg()),
     );
 
     Matcher::transform(ChainMatcher::new(block), format)
@@ -84,8 +182,39 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::all;
-    use crate::{be_gt, be_lt, expect};
+    use super::{all, ChainFormat};
+    use crate::core::{FormattedOutput, MatchFailure, MatchOutcome, PlainRenderer, TransformMatch};
+    use crate::matchers::combinators::ChainMatcher;
+    use crate::{be_gt, be_lt, be_some, expect};
+
+    fn render(format: ChainFormat) -> String {
+        let matcher = ChainMatcher::new(|ctx| ctx.to(be_some())?.to(be_gt(0))?.to(be_lt(3)));
+
+        let outcomes = match matcher.match_pos(Some(5)) {
+            Ok(MatchOutcome::Fail(outcomes)) => outcomes,
+            _ => panic!("expected the matcher to fail"),
+        };
+
+        FormattedOutput::new(MatchFailure::Pos(outcomes), format)
+            .unwrap()
+            .render(&PlainRenderer)
+    }
+
+    #[test]
+    fn only_prints_failure_by_default() {
+        assert_eq!(
+            render(ChainFormat::new().verbose(false)),
+            "Expected:\n    5\nto be less than:\n    3\n",
+        );
+    }
+
+    #[test]
+    fn verbose_prints_matchers_before_failure() {
+        assert_eq!(
+            render(ChainFormat::new().verbose(true)),
+            "[0]  OK\n[1]  OK\n[2]  FAILED\n     Expected:\n         5\n     to be less than:\n         3\n\n",
+        );
+    }
 
     #[test]
     fn succeeds_when_all_matchers_succeed() {
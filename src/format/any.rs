@@ -1,8 +1,7 @@
-use crate::core::style::{ALL_OK_HEADER, AT_LESAT_ONE_OK_HEADER};
-use crate::core::{style, Format, FormattedOutput, Formatter, MatchFailure, Matcher};
+use crate::core::{style, Format, FormattedOutput, Formatter, MatchFailure, Matcher, Message};
 use crate::matchers::combinators::{CombinatorContext, CombinatorMatcher, CombinatorMode};
 
-use super::SomeFailuresFormat;
+use super::SomeOutcomesFormat;
 
 /// A formatter that adds a header to the output of another formatter.
 ///
@@ -19,8 +18,8 @@ use super::SomeFailuresFormat;
 #[derive(Debug, Default)]
 pub struct HeaderFormat<Fmt> {
     inner: Fmt,
-    pos_header: String,
-    neg_header: String,
+    pos_header: Message,
+    neg_header: Message,
 }
 
 impl<Fmt> HeaderFormat<Fmt> {
@@ -29,7 +28,7 @@ impl<Fmt> HeaderFormat<Fmt> {
     /// This accepts two header strings, one for the positive case and one for the negative case
     /// respectively. The first is used normally, and the second is used when the matcher is
     /// negated.
-    pub fn new(inner: Fmt, pos_header: impl Into<String>, neg_header: impl Into<String>) -> Self {
+    pub fn new(inner: Fmt, pos_header: impl Into<Message>, neg_header: impl Into<Message>) -> Self {
         Self {
             inner,
             pos_header: pos_header.into(),
@@ -47,9 +46,9 @@ where
     fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
         f.set_style(style::important());
         if value.is_pos() {
-            f.write_str(&self.pos_header);
+            f.write_str(self.pos_header.resolve());
         } else {
-            f.write_str(&self.neg_header)
+            f.write_str(self.neg_header.resolve())
         }
         f.reset_style();
         f.write_char('\n');
@@ -112,9 +111,43 @@ where
     Matcher::transform(
         CombinatorMatcher::new(CombinatorMode::Any, block),
         HeaderFormat::new(
-            SomeFailuresFormat::new(),
-            AT_LESAT_ONE_OK_HEADER,
-            ALL_OK_HEADER,
+            SomeOutcomesFormat::new(),
+            style::any_ok_header(),
+            style::all_ok_header(),
+        ),
+    )
+}
+
+/// Same as [`any`], but always in verbose mode.
+///
+/// When this matcher fails, it prints every matcher passed to it, marking each one as OK or FAILED,
+/// and describes the matchers which succeeded if they implement [`Match::describe`]. This does the
+/// same thing as enabling [`Config::verbose`], but only for this assertion.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{any_verbose, expect, be_gt, be_lt};
+///
+/// expect!(60).to(any_verbose(|ctx| {
+///     ctx.copied()
+///         .to(be_lt(41))
+///         .to(be_gt(57));
+/// }));
+/// ```
+///
+/// [`Match::describe`]: crate::core::Match::describe
+/// [`Config::verbose`]: crate::Config::verbose
+pub fn any_verbose<'a, T>(block: impl Fn(&mut CombinatorContext<T>) + 'a) -> Matcher<'a, T, T>
+where
+    T: 'a,
+{
+    Matcher::transform(
+        CombinatorMatcher::new(CombinatorMode::Any, block).verbose(true),
+        HeaderFormat::new(
+            SomeOutcomesFormat::new().verbose(true),
+            style::any_ok_header(),
+            style::all_ok_header(),
         ),
     )
 }
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::core::{style, Format, Formatter, MatchFailure, Matcher, NegFormat};
+use crate::core::{style, Format, Formatter, MatchFailure, Matcher, Message, NegFormat};
 use crate::matchers::boolean::BeTrueMatcher;
 
 /// A formatter which prints a static string message.
@@ -24,8 +24,8 @@ use crate::matchers::boolean::BeTrueMatcher;
 #[derive(Debug)]
 pub struct MessageFormat<PosFail = (), NegFail = ()> {
     marker: PhantomData<(PosFail, NegFail)>,
-    pos_msg: String,
-    neg_msg: String,
+    pos_msg: Message,
+    neg_msg: Message,
 }
 
 impl<PosFail, NegFail> MessageFormat<PosFail, NegFail> {
@@ -34,7 +34,7 @@ impl<PosFail, NegFail> MessageFormat<PosFail, NegFail> {
     /// This accepts two error messages: the one to use in the *positive* case (when we were
     /// expecting the matcher to succeed) and the one to use in the *negative* case (when we were
     /// expecting the matcher to fail).
-    pub fn new(pos_msg: impl Into<String>, neg_msg: impl Into<String>) -> Self {
+    pub fn new(pos_msg: impl Into<Message>, neg_msg: impl Into<Message>) -> Self {
         Self {
             marker: PhantomData,
             pos_msg: pos_msg.into(),
@@ -49,9 +49,9 @@ impl<PosFail, NegFail> Format for MessageFormat<PosFail, NegFail> {
     fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
         f.set_style(style::bad());
         if value.is_pos() {
-            f.write_str(&self.pos_msg);
+            f.write_str(self.pos_msg.resolve());
         } else {
-            f.write_str(&self.neg_msg);
+            f.write_str(self.neg_msg.resolve());
         }
         f.reset_style();
         f.write_char('\n');
@@ -61,7 +61,7 @@ impl<PosFail, NegFail> Format for MessageFormat<PosFail, NegFail> {
 }
 
 fn bool_format() -> MessageFormat {
-    MessageFormat::new("Expected this to be true", "Expected this to be false")
+    MessageFormat::new(Message::id("be-true.pos"), Message::id("be-true.neg"))
 }
 
 /// Succeeds when the actual value is `true`.
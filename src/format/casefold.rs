@@ -2,7 +2,7 @@
 
 use std::{borrow::Cow, fmt};
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::strings::EqCasefoldMatcher;
 
 use super::MismatchFormat;
@@ -26,8 +26,8 @@ where
     Matcher::new(
         EqCasefoldMatcher::new(expected),
         MismatchFormat::new(
-            "to equal case-insensitively",
-            "to not equal case-insensitively",
+            Message::id("eq-casefold.pos"),
+            Message::id("eq-casefold.neg"),
         ),
     )
 }
@@ -1,7 +1,7 @@
 use std::borrow::Borrow;
 use std::fmt;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::collections::{
     BeInMatcher, ConsistOfMatcher, ContainElementsMatcher, Contains, Len,
 };
@@ -27,7 +27,10 @@ where
 {
     Matcher::new(
         ContainElementsMatcher::new([element]),
-        MismatchFormat::new("to contain elements", "to not contain elements"),
+        MismatchFormat::new(
+            Message::id("contain-elements.pos"),
+            Message::id("contain-elements.neg"),
+        ),
     )
 }
 
@@ -52,7 +55,10 @@ where
 {
     Matcher::new(
         ContainElementsMatcher::new(elements),
-        MismatchFormat::new("to contain elements", "to not contain elements"),
+        MismatchFormat::new(
+            Message::id("contain-elements.pos"),
+            Message::id("contain-elements.neg"),
+        ),
     )
 }
 
@@ -76,7 +82,7 @@ where
 {
     Matcher::new(
         ConsistOfMatcher::new(elements),
-        MismatchFormat::new("to consist of elements", "to consist of elements"),
+        MismatchFormat::new(Message::id("consist-of.pos"), Message::id("consist-of.neg")),
     )
 }
 
@@ -100,7 +106,7 @@ where
 {
     Matcher::new(
         BeInMatcher::new(collection),
-        MismatchFormat::new("to be in", "to not be in"),
+        MismatchFormat::new(Message::id("be-in.pos"), Message::id("be-in.neg")),
     )
 }
 
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::default::BeDefaultMatcher;
 
 use super::MismatchFormat;
@@ -20,7 +20,7 @@ where
 {
     Matcher::new(
         BeDefaultMatcher::new(),
-        MismatchFormat::new("to be the default value", "to not be the default value"),
+        MismatchFormat::new(Message::id("be-default.pos"), Message::id("be-default.neg")),
     )
 }
 
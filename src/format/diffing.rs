@@ -1,13 +1,23 @@
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use crate::core::{
-    strings, style, Color, Format, Formatter, MatchFailure, Matcher, OutputStyle, TextColor,
-    TextStyle,
+    config, message, message_with, strings, style, terminal, Format, Formatter, MatchFailure,
+    Matcher, OutputStyle, TextStyle,
+};
+use crate::matchers::diff::{
+    hex_row_cells, is_no_newline_marker, BytesDiff, DebugDiff, Diff, DiffGranularity, DiffKind,
+    DiffOptions, DiffSegment, DiffTag, Diffable, EqBytesMatcher, EqDebugDiffMatcher, EqDiffMatcher,
+    HexCell,
 };
-use crate::matchers::diff::{Diff, DiffKind, DiffTag, Diffable, EqDiffMatcher};
 
 const FORMAT_PLACEHOLDER: &str = "%s";
+const GUTTER_WIDTH: usize = 2;
+const COLUMN_SEPARATOR: &str = " │ ";
+
+/// A piece of text and the style to show it in.
+type StyledText = (String, OutputStyle);
 
 /// A configuration option for [`DiffStyle`].
 ///
@@ -64,20 +74,8 @@ impl StringDiffStyle {
     pub fn provided() -> Self {
         Self {
             style: DiffSegmentStyle {
-                insert: OutputStyle {
-                    style: TextStyle::BOLD | TextStyle::REVERSED,
-                    color: TextColor {
-                        fg: Some(Color::BrightGreen),
-                        bg: None,
-                    },
-                },
-                delete: OutputStyle {
-                    style: TextStyle::BOLD | TextStyle::UNDERLINE,
-                    color: TextColor {
-                        fg: Some(Color::BrightRed),
-                        bg: None,
-                    },
-                },
+                insert: style::inserted(TextStyle::BOLD | TextStyle::REVERSED),
+                delete: style::deleted(TextStyle::BOLD | TextStyle::UNDERLINE),
                 equal: OutputStyle::default(),
             },
             format: DiffSegmentStyle {
@@ -133,20 +131,8 @@ impl CollectionDiffStyle {
     pub fn provided() -> Self {
         Self {
             element_style: DiffSegmentStyle {
-                insert: OutputStyle {
-                    style: TextStyle::BOLD,
-                    color: TextColor {
-                        fg: Some(Color::BrightGreen),
-                        bg: None,
-                    },
-                },
-                delete: OutputStyle {
-                    style: TextStyle::BOLD,
-                    color: TextColor {
-                        fg: Some(Color::BrightRed),
-                        bg: None,
-                    },
-                },
+                insert: style::inserted(TextStyle::BOLD),
+                delete: style::deleted(TextStyle::BOLD),
                 equal: OutputStyle::default(),
             },
             gutter_char: DiffSegmentStyle {
@@ -155,26 +141,37 @@ impl CollectionDiffStyle {
                 equal: ' ',
             },
             gutter_style: DiffSegmentStyle {
-                insert: OutputStyle {
-                    style: TextStyle::empty(),
-                    color: TextColor {
-                        fg: Some(Color::BrightGreen),
-                        bg: None,
-                    },
-                },
-                delete: OutputStyle {
-                    style: TextStyle::empty(),
-                    color: TextColor {
-                        fg: Some(Color::BrightRed),
-                        bg: None,
-                    },
-                },
+                insert: style::inserted(TextStyle::empty()),
+                delete: style::deleted(TextStyle::empty()),
                 equal: OutputStyle::default(),
             },
         }
     }
 }
 
+impl CollectionDiffStyle {
+    /// Return the gutter char, gutter style, and element style for the given tag.
+    fn for_tag(&self, tag: DiffTag) -> (char, OutputStyle, OutputStyle) {
+        match tag {
+            DiffTag::Insert => (
+                self.gutter_char.insert,
+                self.gutter_style.insert.clone(),
+                self.element_style.insert.clone(),
+            ),
+            DiffTag::Delete => (
+                self.gutter_char.delete,
+                self.gutter_style.delete.clone(),
+                self.element_style.delete.clone(),
+            ),
+            DiffTag::Equal => (
+                self.gutter_char.equal,
+                self.gutter_style.equal.clone(),
+                self.element_style.equal.clone(),
+            ),
+        }
+    }
+}
+
 impl Default for CollectionDiffStyle {
     fn default() -> Self {
         Self {
@@ -197,11 +194,32 @@ impl Default for CollectionDiffStyle {
     }
 }
 
+/// How [`DiffFormat`] lays out the expected and actual values of a diff.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiffLayout {
+    /// Show the changes in a single column, with `+` and `-` in the gutter, like `git diff`.
+    #[default]
+    Interleaved,
+
+    /// Show the expected value and the actual value in two columns, with the rows aligned.
+    ///
+    /// This only applies to diffs of collections, diffs of structured data, and line diffs. If
+    /// the columns don't fit in the width of the terminal, this falls back to [`Interleaved`].
+    ///
+    /// The width of the terminal is read from the `COLUMNS` environment variable if it's set, and
+    /// otherwise from the terminal attached to stderr. If the width can't be determined, it's
+    /// assumed to be 80 columns.
+    ///
+    /// [`Interleaved`]: crate::format::diff::DiffLayout::Interleaved
+    SideBySide,
+}
+
 /// The style sheet for [`DiffFormat`].
 ///
 /// If the provided text styling for diffs is inaccessible for you, or you prefer to have text
 /// styling disabled, you can use this style sheet to customize the styling of diffs.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct DiffStyle {
     /// The styling for string diffs.
@@ -209,6 +227,23 @@ pub struct DiffStyle {
 
     /// The styling for diffs of collections.
     pub collection: CollectionDiffStyle,
+
+    /// The number of unchanged lines or elements to show around each change.
+    ///
+    /// Long runs of unchanged lines or elements outside of this context are collapsed into a
+    /// single line, like `… 1,482 unchanged lines …`. Line diffs and diffs of slices also show a
+    /// `@@ -a,b +c,d @@` header before each hunk of changes, like `git diff`.
+    ///
+    /// If this is `None`, the whole diff is shown. This defaults to [`Config::diff_context`].
+    ///
+    /// [`Config::diff_context`]: crate::core::Config::diff_context
+    pub context: Option<usize>,
+
+    /// The text styling to use for hunk headers and collapsed runs of unchanged lines or elements.
+    pub hunk_style: OutputStyle,
+
+    /// How to lay out the expected and actual values.
+    pub layout: DiffLayout,
 }
 
 impl DiffStyle {
@@ -220,6 +255,21 @@ impl DiffStyle {
         Self {
             string: StringDiffStyle::provided(),
             collection: CollectionDiffStyle::provided(),
+            context: config().diff_context,
+            hunk_style: style::info(),
+            layout: DiffLayout::Interleaved,
+        }
+    }
+}
+
+impl Default for DiffStyle {
+    fn default() -> Self {
+        Self {
+            string: StringDiffStyle::default(),
+            collection: CollectionDiffStyle::default(),
+            context: config().diff_context,
+            hunk_style: OutputStyle::default(),
+            layout: DiffLayout::Interleaved,
         }
     }
 }
@@ -230,19 +280,548 @@ impl DiffStyle {
 #[derive(Debug)]
 pub struct DiffFormat<Actual, Expected> {
     style: DiffStyle,
+    options: DiffOptions,
     marker: PhantomData<(Actual, Expected)>,
 }
 
 impl<Actual, Expected> DiffFormat<Actual, Expected> {
     /// Create a new [`DiffFormat`] from the given style sheet.
     pub fn new(style: DiffStyle) -> Self {
+        Self::with_options(style, DiffOptions::default())
+    }
+
+    /// Create a new [`DiffFormat`] from the given style sheet and the options the diff was
+    /// generated with.
+    ///
+    /// The options tell this formatter how to render the diff. For example, strings diffed with
+    /// [`DiffGranularity::Line`] are rendered line by line.
+    ///
+    /// [`DiffGranularity::Line`]: crate::matchers::diff::DiffGranularity::Line
+    pub fn with_options(style: DiffStyle, options: DiffOptions) -> Self {
         Self {
             style,
+            options,
             marker: PhantomData,
         }
     }
 }
 
+impl StringDiffStyle {
+    /// Return the format string and text style for the given tag.
+    fn for_tag(&self, tag: DiffTag) -> (String, OutputStyle) {
+        match tag {
+            DiffTag::Insert => (self.format.insert.clone(), self.style.insert.clone()),
+            DiffTag::Delete => (self.format.delete.clone(), self.style.delete.clone()),
+            DiffTag::Equal => (self.format.equal.clone(), self.style.equal.clone()),
+        }
+    }
+}
+
+/// For each line in a line diff, return the index of the line it replaced or was replaced by.
+///
+/// A run of deleted lines followed immediately by a run of inserted lines is treated as a
+/// replacement, and the lines in each run are paired up in order. Lines without a partner are
+/// pure insertions or deletions.
+fn pair_replaced_lines(diff: &Diff) -> Vec<Option<usize>> {
+    let mut partners = vec![None; diff.len()];
+    let mut index = 0;
+
+    while index < diff.len() {
+        let run_len = |start: usize, tag: DiffTag| {
+            diff[start..]
+                .iter()
+                .take_while(|segment| segment.tag == tag)
+                .count()
+        };
+
+        let deleted = run_len(index, DiffTag::Delete);
+        let inserted = run_len(index + deleted, DiffTag::Insert);
+
+        for offset in 0..deleted.min(inserted) {
+            let delete_index = index + offset;
+            let insert_index = index + deleted + offset;
+
+            partners[delete_index] = Some(insert_index);
+            partners[insert_index] = Some(delete_index);
+        }
+
+        index += (deleted + inserted).max(1);
+    }
+
+    partners
+}
+
+/// A contiguous range of a diff which contains changes, along with the context around them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hunk {
+    range: Range<usize>,
+    expected: (usize, usize),
+    actual: (usize, usize),
+}
+
+impl fmt::Display for Hunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Like `git diff`, positions start at 1, except an empty range starts at the position
+        // before it.
+        let start =
+            |(position, len): (usize, usize)| if len == 0 { position } else { position + 1 };
+
+        write!(
+            f,
+            "@@ -{},{} +{},{} @@",
+            start(self.expected),
+            self.expected.1,
+            start(self.actual),
+            self.actual.1
+        )
+    }
+}
+
+/// A row in a diff with context folding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Row {
+    /// The header at the start of a hunk.
+    Header(Hunk),
+
+    /// A run of this many unchanged lines or elements that was collapsed.
+    Folded(usize),
+
+    /// The segment in the diff at this index.
+    Segment(usize),
+}
+
+/// Split a diff into hunks of changes with `context` unchanged segments around each change.
+///
+/// A fold is never a single segment, because the "… 1 unchanged line …" message takes up as much
+/// space as the line it replaces. So hunks which are separated by one unchanged segment are merged,
+/// and a single unchanged segment at the start or end of the diff is included in the hunk next to
+/// it.
+///
+/// The `\ No newline at end of file` marker in a line diff isn't a line, so it's kept in the same
+/// hunk as the line before it, and it doesn't count towards the context or the line numbers in the
+/// hunk headers.
+///
+/// This returns an empty list if there are no changes.
+fn find_hunks(diff: &Diff, context: usize) -> Vec<Hunk> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+
+    for (index, _) in diff.iter().enumerate().filter(|(index, segment)| {
+        segment.tag != DiffTag::Equal && !is_no_newline_marker(diff, *index)
+    }) {
+        let marked = index + 1 < diff.len() && is_no_newline_marker(diff, index + 1);
+        let end = index + context + 1 + usize::from(marked);
+        let range = index.saturating_sub(context)..end.min(diff.len());
+
+        match ranges.last_mut() {
+            Some(last) if range.start <= last.end + 1 => last.end = range.end,
+            _ => ranges.push(range),
+        }
+    }
+
+    if let Some(first) = ranges.first_mut() {
+        if first.start == 1 {
+            first.start = 0;
+        }
+    }
+
+    if let Some(last) = ranges.last_mut() {
+        if last.end + 1 == diff.len() {
+            last.end = diff.len();
+        }
+    }
+
+    let count = |range: Range<usize>, skip: DiffTag| {
+        range
+            .filter(|&index| diff[index].tag != skip && !is_no_newline_marker(diff, index))
+            .count()
+    };
+
+    ranges
+        .into_iter()
+        .map(|range| Hunk {
+            expected: (
+                count(0..range.start, DiffTag::Insert),
+                count(range.clone(), DiffTag::Insert),
+            ),
+            actual: (
+                count(0..range.start, DiffTag::Delete),
+                count(range.clone(), DiffTag::Delete),
+            ),
+            range,
+        })
+        .collect()
+}
+
+/// Return the rows to show for a diff, collapsing unchanged segments outside of `context`.
+fn fold_rows(diff: &Diff, context: Option<usize>, headers: bool) -> Vec<Row> {
+    let hunks = match context {
+        Some(context) => find_hunks(diff, context),
+        None => Vec::new(),
+    };
+
+    if hunks.is_empty() {
+        return (0..diff.len()).map(Row::Segment).collect();
+    }
+
+    let mut rows = Vec::new();
+    let mut end = 0;
+
+    for hunk in hunks {
+        if hunk.range.start > end {
+            rows.push(Row::Folded(hunk.range.start - end));
+        }
+
+        end = hunk.range.end;
+        let range = hunk.range.clone();
+
+        if headers {
+            rows.push(Row::Header(hunk));
+        }
+
+        rows.extend(range.map(Row::Segment));
+    }
+
+    if diff.len() > end {
+        rows.push(Row::Folded(diff.len() - end));
+    }
+
+    rows
+}
+
+/// The message for a collapsed run of `count` unchanged lines or elements.
+fn folded_msg(count: usize, id: &str) -> String {
+    message_with(id, Some(count), &[])
+}
+
+/// An unchanged substring of a string diff with the unchanged lines in the middle collapsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FoldedString {
+    /// The text before the collapsed lines, if there are changes before this substring.
+    front: Option<String>,
+
+    /// The number of collapsed lines.
+    folded: usize,
+
+    /// The text after the collapsed lines, if there are changes after this substring.
+    back: Option<String>,
+}
+
+/// Collapse the unchanged lines in the middle of an unchanged substring of a string diff.
+///
+/// `has_prev` and `has_next` are whether there are changes before and after this substring. The
+/// parts of the first and last lines that are on the same line as a change are always kept, along
+/// with `context` lines after and before them.
+fn fold_string(
+    value: &str,
+    context: usize,
+    has_prev: bool,
+    has_next: bool,
+) -> Option<FoldedString> {
+    let lines = value.split('\n').collect::<Vec<_>>();
+
+    // A trailing newline at the very end of the string doesn't start another line.
+    let total_len = if !has_next && value.ends_with('\n') {
+        lines.len() - 1
+    } else {
+        lines.len()
+    };
+
+    let front_len = if has_prev { context + 1 } else { 0 };
+    let back_len = if has_next { context + 1 } else { 0 };
+
+    // Collapsing a single line wouldn't save any space.
+    if front_len + back_len + 1 >= total_len {
+        return None;
+    }
+
+    Some(FoldedString {
+        front: has_prev.then(|| lines[..front_len].join("\n")),
+        folded: total_len - front_len - back_len,
+        back: has_next.then(|| lines[lines.len() - back_len..].join("\n")),
+    })
+}
+
+/// A row in a side-by-side diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SideBySideRow {
+    /// The header at the start of a hunk.
+    Header(Hunk),
+
+    /// A run of this many unchanged lines or elements that was collapsed.
+    Folded(usize),
+
+    /// The indices of the segments to show in the expected and actual columns.
+    Pair(Option<usize>, Option<usize>),
+}
+
+/// Line up the rows of a diff into two columns.
+///
+/// Unchanged segments appear in both columns. Deleted segments appear in the expected column
+/// across from the inserted segment that replaced them, if there is one.
+fn side_by_side_rows(
+    diff: &Diff,
+    partners: &[Option<usize>],
+    rows: Vec<Row>,
+) -> Vec<SideBySideRow> {
+    rows.into_iter()
+        .filter_map(|row| match row {
+            Row::Header(hunk) => Some(SideBySideRow::Header(hunk)),
+            Row::Folded(count) => Some(SideBySideRow::Folded(count)),
+            Row::Segment(index) => match (diff[index].tag, partners[index]) {
+                (DiffTag::Equal, _) => Some(SideBySideRow::Pair(Some(index), Some(index))),
+                (DiffTag::Delete, partner) => Some(SideBySideRow::Pair(Some(index), partner)),
+                (DiffTag::Insert, None) => Some(SideBySideRow::Pair(None, Some(index))),
+                // This was already shown across from the segment it replaced.
+                (DiffTag::Insert, Some(_)) => None,
+            },
+        })
+        .collect()
+}
+
+/// A row in a side-by-side diff, with the contents of each column.
+enum SideBySideCells {
+    Header(Hunk),
+    Folded(usize),
+    Pair((DiffTag, Vec<StyledText>), (DiffTag, Vec<StyledText>)),
+}
+
+fn pieces_width(pieces: &[StyledText]) -> usize {
+    pieces.iter().map(|(text, _)| text.chars().count()).sum()
+}
+
+fn fmt_pieces(f: &mut Formatter, pieces: Vec<StyledText>) {
+    for (text, style) in pieces {
+        f.set_style(style);
+        f.write_str(text);
+        f.reset_style();
+    }
+}
+
+impl<Actual, Expected> DiffFormat<Actual, Expected> {
+    fn fmt_folded(&self, f: &mut Formatter, count: usize, id: &str) {
+        f.set_style(self.style.hunk_style.clone());
+        f.write_str(folded_msg(count, id));
+        f.reset_style();
+    }
+
+    fn fmt_header(&self, f: &mut Formatter, hunk: &Hunk) {
+        f.set_style(self.style.hunk_style.clone());
+        f.write_str(hunk.to_string());
+        f.reset_style();
+    }
+
+    /// Return the styled pieces of text to show for a line or element in a diff.
+    ///
+    /// If this line replaced another line or was replaced by another line, this highlights what
+    /// changed within it.
+    fn line_pieces(&self, line: &DiffSegment, partner: Option<&DiffSegment>) -> Vec<StyledText> {
+        let (_, _, element_style) = self.style.collection.for_tag(line.tag);
+
+        let partner = match partner {
+            Some(partner) => partner,
+            None => return vec![(line.value.clone(), element_style)],
+        };
+
+        // Diff the line against the line it replaced to highlight what changed within it. The
+        // deleted line only shows the deleted parts, and the inserted line only shows the inserted
+        // parts.
+        let options = DiffOptions {
+            algorithm: self.options.algorithm,
+            ..Default::default()
+        };
+
+        let intra_line_diff = match line.tag {
+            DiffTag::Delete => line
+                .value
+                .as_str()
+                .diff_with(partner.value.as_str(), &options),
+            _ => partner
+                .value
+                .as_str()
+                .diff_with(line.value.as_str(), &options),
+        };
+
+        intra_line_diff
+            .into_iter()
+            .filter_map(|segment| {
+                if segment.tag == DiffTag::Equal {
+                    Some((segment.value, element_style.clone()))
+                } else if segment.tag == line.tag {
+                    let (format, style) = self.style.string.for_tag(segment.tag);
+                    Some((
+                        format.replacen(FORMAT_PLACEHOLDER, &segment.value, 1),
+                        style,
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn fmt_gutter(&self, f: &mut Formatter, tag: DiffTag) {
+        let (gutter, gutter_style, _) = self.style.collection.for_tag(tag);
+
+        f.set_style(gutter_style);
+        f.write_char(gutter);
+        f.reset_style();
+        f.write_char(' ');
+    }
+
+    /// Format a row of a hex dump.
+    ///
+    /// If this row replaced another row or was replaced by another row, this highlights the
+    /// columns which differ from it.
+    fn fmt_bytes_row(&self, f: &mut Formatter, row: &DiffSegment, partner: Option<&DiffSegment>) {
+        let (_, _, element_style) = self.style.collection.for_tag(row.tag);
+        let (format, changed_style) = self.style.string.for_tag(row.tag);
+
+        let partner_cells = partner.map(|partner| hex_row_cells(&partner.value));
+
+        let pieces = hex_row_cells(&row.value)
+            .into_iter()
+            .map(|(cell, text)| match cell {
+                HexCell::Offset => (text.to_string(), self.style.hunk_style.clone()),
+                HexCell::Separator => (text.to_string(), OutputStyle::default()),
+                HexCell::Byte(_) | HexCell::Char(_) => {
+                    let changed = partner_cells
+                        .as_ref()
+                        .is_some_and(|partner_cells| !partner_cells.contains(&(cell, text)));
+
+                    if changed {
+                        (
+                            format.replacen(FORMAT_PLACEHOLDER, text, 1),
+                            changed_style.clone(),
+                        )
+                    } else {
+                        (text.to_string(), element_style.clone())
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.fmt_gutter(f, row.tag);
+        fmt_pieces(f, pieces);
+    }
+
+    fn fmt_line(&self, f: &mut Formatter, line: &DiffSegment, partner: Option<&DiffSegment>) {
+        self.fmt_gutter(f, line.tag);
+        fmt_pieces(f, self.line_pieces(line, partner));
+    }
+
+    /// Format the diff in two columns, with the expected value on the left and the actual value on
+    /// the right.
+    ///
+    /// This returns `false` without writing anything if the columns don't fit in the terminal.
+    fn fmt_side_by_side(&self, f: &mut Formatter, diff: &Diff, kind: DiffKind) -> bool {
+        let folded_id = match kind {
+            DiffKind::Line => "diff.unchanged-lines",
+            DiffKind::Map => "diff.unchanged-entries",
+            DiffKind::KeyPath => "diff.unchanged-values",
+            _ => "diff.unchanged-elements",
+        };
+        let expected_title = message("diff.expected");
+        let actual_title = message("diff.actual");
+
+        let headers = matches!(kind, DiffKind::Line | DiffKind::Slice);
+        let partners = pair_replaced_lines(diff);
+
+        let cell = |index: Option<usize>, partner: Option<usize>| match index {
+            Some(index) => (
+                diff[index].tag,
+                self.line_pieces(&diff[index], partner.map(|partner| &diff[partner])),
+            ),
+            None => (DiffTag::Equal, Vec::new()),
+        };
+
+        let rows = side_by_side_rows(
+            diff,
+            &partners,
+            fold_rows(diff, self.style.context, headers),
+        )
+        .into_iter()
+        .map(|row| match row {
+            SideBySideRow::Header(hunk) => SideBySideCells::Header(hunk),
+            SideBySideRow::Folded(count) => SideBySideCells::Folded(count),
+            SideBySideRow::Pair(expected, actual) => SideBySideCells::Pair(
+                cell(expected, actual.filter(|_| expected != actual)),
+                cell(actual, expected.filter(|_| expected != actual)),
+            ),
+        })
+        .collect::<Vec<_>>();
+
+        let mut column_width = expected_title
+            .chars()
+            .count()
+            .max(actual_title.chars().count());
+
+        for row in &rows {
+            if let SideBySideCells::Pair((_, expected), (_, actual)) = row {
+                for pieces in [expected, actual] {
+                    if pieces.iter().any(|(text, _)| text.contains('\n')) {
+                        return false;
+                    }
+
+                    column_width = column_width.max(pieces_width(pieces));
+                }
+            }
+        }
+
+        // Each column has a gutter, and the columns are separated by a divider.
+        let total_width = style::indent_len(1) as usize
+            + (GUTTER_WIDTH + column_width) * 2
+            + COLUMN_SEPARATOR.chars().count();
+
+        if total_width > terminal::width().unwrap_or(terminal::DEFAULT_WIDTH) {
+            return false;
+        }
+
+        f.indented(style::indent(1), |f| {
+            f.set_style(style::important());
+            f.write_str(strings::whitespace(GUTTER_WIDTH));
+            f.write_str(&expected_title);
+            f.write_str(strings::whitespace(
+                column_width - expected_title.chars().count(),
+            ));
+            f.write_str(COLUMN_SEPARATOR);
+            f.write_str(strings::whitespace(GUTTER_WIDTH));
+            f.write_str(&actual_title);
+            f.reset_style();
+
+            for row in rows {
+                f.write_char('\n');
+
+                match row {
+                    SideBySideCells::Header(hunk) => self.fmt_header(f, &hunk),
+                    SideBySideCells::Folded(count) => {
+                        f.write_str(strings::whitespace(GUTTER_WIDTH));
+                        self.fmt_folded(f, count, folded_id);
+                    }
+                    SideBySideCells::Pair((expected_tag, expected), (actual_tag, actual)) => {
+                        let expected_width = pieces_width(&expected);
+
+                        self.fmt_gutter(f, expected_tag);
+                        fmt_pieces(f, expected);
+                        f.write_str(strings::whitespace(column_width - expected_width));
+                        // Don't leave trailing whitespace when the actual column is empty.
+                        if actual.is_empty() && actual_tag == DiffTag::Equal {
+                            f.write_str(COLUMN_SEPARATOR.trim_end());
+                            continue;
+                        }
+
+                        f.write_str(COLUMN_SEPARATOR);
+                        self.fmt_gutter(f, actual_tag);
+                        fmt_pieces(f, actual);
+                    }
+                }
+            }
+
+            Ok(())
+        })
+        .is_ok()
+    }
+}
+
 impl<Actual, Expected> Format for DiffFormat<Actual, Expected>
 where
     Actual: fmt::Debug,
@@ -256,37 +835,77 @@ where
         f.set_style(style::important());
 
         if value.is_pos() {
-            f.write_str("Expected these to be equal:\n");
+            f.write_str(message("diff.pos"));
         } else {
-            f.write_str("Expected these to not be equal:\n");
+            f.write_str(message("diff.neg"));
         }
 
+        f.write_char('\n');
+
         f.reset_style();
 
-        match Expected::KIND {
+        let kind = match (Expected::KIND, self.options.granularity) {
+            (DiffKind::String, DiffGranularity::Line) => DiffKind::Line,
+            (kind, _) => kind,
+        };
+
+        let supports_side_by_side = matches!(
+            kind,
+            DiffKind::Slice | DiffKind::Set | DiffKind::Map | DiffKind::KeyPath | DiffKind::Line
+        );
+
+        if self.style.layout == DiffLayout::SideBySide
+            && supports_side_by_side
+            && self.fmt_side_by_side(f, diff, kind)
+        {
+            return Ok(());
+        }
+
+        match kind {
             DiffKind::String => {
+                let changes = diff
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, segment)| segment.tag != DiffTag::Equal)
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>();
+
                 f.indented(style::indent(1), |f| {
-                    for segment in diff {
-                        let (format, style) = match segment.tag {
-                            DiffTag::Insert => (
-                                self.style.string.format.insert.clone(),
-                                self.style.string.style.insert.clone(),
-                            ),
-                            DiffTag::Delete => (
-                                self.style.string.format.delete.clone(),
-                                self.style.string.style.delete.clone(),
-                            ),
-                            DiffTag::Equal => (
-                                self.style.string.format.equal.clone(),
-                                self.style.string.style.equal.clone(),
+                    for (i, segment) in diff.iter().enumerate() {
+                        let (format, style) = self.style.string.for_tag(segment.tag);
+
+                        let folded = match (self.style.context, segment.tag) {
+                            (Some(context), DiffTag::Equal) if !changes.is_empty() => fold_string(
+                                &segment.value,
+                                context,
+                                changes.first().is_some_and(|&first| first < i),
+                                changes.last().is_some_and(|&last| last > i),
                             ),
+                            _ => None,
+                        };
+
+                        let folded = match folded {
+                            Some(folded) => folded,
+                            None => {
+                                f.set_style(style);
+                                f.write_str(format.replacen(FORMAT_PLACEHOLDER, &segment.value, 1));
+                                continue;
+                            }
                         };
 
-                        let formatted_segment =
-                            format.replacen(FORMAT_PLACEHOLDER, &segment.value, 1);
+                        if let Some(front) = &folded.front {
+                            f.set_style(style.clone());
+                            f.write_str(format.replacen(FORMAT_PLACEHOLDER, front, 1));
+                            f.write_char('\n');
+                        }
 
-                        f.set_style(style);
-                        f.write_str(&formatted_segment);
+                        self.fmt_folded(f, folded.folded, "diff.unchanged-lines");
+
+                        if let Some(back) = &folded.back {
+                            f.write_char('\n');
+                            f.set_style(style);
+                            f.write_str(format.replacen(FORMAT_PLACEHOLDER, back, 1));
+                        }
                     }
 
                     Ok(())
@@ -296,7 +915,7 @@ where
             }
             DiffKind::Slice | DiffKind::Set | DiffKind::Map => {
                 f.indented(style::indent(1), |f| {
-                    match Expected::KIND {
+                    match kind {
                         DiffKind::Slice => f.write_char('['),
                         DiffKind::Set | DiffKind::Map => f.write_char('{'),
                         _ => unreachable!(),
@@ -304,25 +923,33 @@ where
 
                     f.write_char('\n');
 
-                    for segment in diff {
-                        let (gutter, gutter_style, element_style) = match segment.tag {
-                            DiffTag::Insert => (
-                                self.style.collection.gutter_char.insert,
-                                self.style.collection.gutter_style.insert.clone(),
-                                self.style.collection.element_style.insert.clone(),
-                            ),
-                            DiffTag::Delete => (
-                                self.style.collection.gutter_char.delete,
-                                self.style.collection.gutter_style.delete.clone(),
-                                self.style.collection.element_style.delete.clone(),
-                            ),
-                            DiffTag::Equal => (
-                                self.style.collection.gutter_char.equal,
-                                self.style.collection.gutter_style.equal.clone(),
-                                self.style.collection.element_style.equal.clone(),
-                            ),
+                    let folded_id = match kind {
+                        DiffKind::Map => "diff.unchanged-entries",
+                        _ => "diff.unchanged-elements",
+                    };
+
+                    let headers = kind == DiffKind::Slice;
+
+                    for row in fold_rows(diff, self.style.context, headers) {
+                        let segment = match row {
+                            Row::Header(hunk) => {
+                                f.write_str(style::indent(1));
+                                self.fmt_header(f, &hunk);
+                                f.write_char('\n');
+                                continue;
+                            }
+                            Row::Folded(count) => {
+                                f.write_str(style::indent(1));
+                                self.fmt_folded(f, count, folded_id);
+                                f.write_char('\n');
+                                continue;
+                            }
+                            Row::Segment(i) => &diff[i],
                         };
 
+                        let (gutter, gutter_style, element_style) =
+                            self.style.collection.for_tag(segment.tag);
+
                         f.set_style(gutter_style);
                         f.write_char(' ');
                         f.write_char(gutter);
@@ -343,7 +970,7 @@ where
                         f.write_char('\n');
                     }
 
-                    match Expected::KIND {
+                    match kind {
                         DiffKind::Slice => f.write_char(']'),
                         DiffKind::Set | DiffKind::Map => f.write_char('}'),
                         _ => unreachable!(),
@@ -354,6 +981,93 @@ where
 
                 Ok(())
             }
+            DiffKind::KeyPath => {
+                f.indented(style::indent(1), |f| {
+                    for (i, segment) in diff.iter().enumerate() {
+                        let (gutter, gutter_style, element_style) =
+                            self.style.collection.for_tag(segment.tag);
+
+                        if i > 0 {
+                            f.write_char('\n');
+                        }
+
+                        f.set_style(gutter_style);
+                        f.write_char(gutter);
+                        f.reset_style();
+                        f.write_char(' ');
+
+                        f.set_style(element_style);
+                        f.write_str(&segment.value);
+                        f.reset_style();
+                    }
+
+                    Ok(())
+                })?;
+
+                Ok(())
+            }
+            DiffKind::Line => {
+                let partners = pair_replaced_lines(diff);
+
+                f.indented(style::indent(1), |f| {
+                    let rows = fold_rows(diff, self.style.context, true);
+
+                    for (row_index, row) in rows.into_iter().enumerate() {
+                        if row_index > 0 {
+                            f.write_char('\n');
+                        }
+
+                        match row {
+                            Row::Header(hunk) => self.fmt_header(f, &hunk),
+                            Row::Folded(count) => {
+                                // Leave room for the gutter char.
+                                f.write_str("  ");
+                                self.fmt_folded(f, count, "diff.unchanged-lines");
+                            }
+                            Row::Segment(i) => self.fmt_line(
+                                f,
+                                &diff[i],
+                                partners[i].map(|partner| &diff[partner]),
+                            ),
+                        }
+                    }
+
+                    Ok(())
+                })?;
+
+                Ok(())
+            }
+            DiffKind::Bytes => {
+                let partners = pair_replaced_lines(diff);
+
+                f.indented(style::indent(1), |f| {
+                    let rows = fold_rows(diff, self.style.context, false);
+
+                    for (row_index, row) in rows.into_iter().enumerate() {
+                        if row_index > 0 {
+                            f.write_char('\n');
+                        }
+
+                        match row {
+                            Row::Header(hunk) => self.fmt_header(f, &hunk),
+                            Row::Folded(count) => {
+                                // Leave room for the gutter char.
+                                f.write_str("  ");
+                                self.fmt_folded(f, count, "diff.unchanged-rows");
+                            }
+                            Row::Segment(i) => self.fmt_bytes_row(
+                                f,
+                                &diff[i],
+                                partners[i].map(|partner| &diff[partner]),
+                            ),
+                        }
+                    }
+
+                    Ok(())
+                })?;
+
+                Ok(())
+            }
             DiffKind::Custom(name) => Err(crate::Error::msg(format!(
                 "this is not a supported diffable kind: {name}",
             ))),
@@ -367,7 +1081,11 @@ where
 /// not equal. You can use this matcher with any type that implements [`Diffable`], and you can
 /// implement [`Diffable`] for your own types.
 ///
-/// Out of the box, you can diff strings, slices, sets, and maps.
+/// Out of the box, you can diff strings, slices, sets, maps, other standard collections, tuples,
+/// and [`Option`]s. With the `derive` feature enabled, you can derive [`Diffable`] for your own
+/// structs and enums. With the `json`, `yaml`, or `toml` features enabled, you can also diff
+/// [`serde_json::Value`]s, [`serde_yaml::Value`]s, or [`toml::Value`]s, which shows each value that
+/// differs along with its path, like `$.user.roles[2]`.
 ///
 /// # Examples
 ///
@@ -435,10 +1153,132 @@ where
     )
 }
 
+/// Succeeds when the actual value equals the expected value and shows a diff generated with the given
+/// options otherwise.
+///
+/// This is like [`eq_diff`], except you can choose how the values are diffed using
+/// [`DiffOptions`]. For strings, you can choose whether to diff by character, word, line, or
+/// grapheme. Strings diffed by line also highlight the changes within each line that differs. You
+/// can also choose which diff algorithm to use.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{expect, eq_diff_with};
+/// use xpct::matchers::diff::{DiffAlgorithm, DiffGranularity, DiffOptions};
+///
+/// let options = DiffOptions {
+///     granularity: DiffGranularity::Line,
+///     algorithm: DiffAlgorithm::Myers,
+/// };
+///
+/// expect!("first line\nsecond line").to(eq_diff_with("first line\nsecond line", options));
+/// ```
+///
+/// [`DiffOptions`]: crate::matchers::diff::DiffOptions
+pub fn eq_diff_with<'a, Actual, Expected>(
+    expected: Expected,
+    options: DiffOptions,
+) -> Matcher<'a, Actual, Actual>
+where
+    Actual: fmt::Debug + PartialEq<Expected> + Eq + 'a,
+    Expected: fmt::Debug + Diffable<Actual> + 'a,
+{
+    Matcher::new(
+        EqDiffMatcher::with_options(expected, options),
+        DiffFormat::<Actual, Expected>::with_options(DiffStyle::provided(), options),
+    )
+}
+
+/// Succeeds when the actual value equals the expected value and shows a diff of their [`Debug`]
+/// representations otherwise.
+///
+/// This is like [`eq_diff`], except it works with any type that implements [`Debug`] and
+/// [`PartialEq`], like your own structs, instead of only types that implement [`Diffable`]. Both
+/// values are pretty-printed with `{:#?}` and diffed line by line, and the changes within each line
+/// that differs are highlighted.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{expect, eq_debug_diff};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Person {
+///     name: String,
+///     id: u32,
+/// }
+///
+/// let value = Person {
+///     name: String::from("Kim Kitsuragi"),
+///     id: 57,
+/// };
+///
+/// expect!(value).to(eq_debug_diff(Person {
+///     name: String::from("Kim Kitsuragi"),
+///     id: 57,
+/// }));
+/// ```
+///
+/// [`Debug`]: std::fmt::Debug
+/// [`Diffable`]: crate::matchers::diff::Diffable
+pub fn eq_debug_diff<'a, Actual, Expected>(expected: Expected) -> Matcher<'a, Actual, Actual>
+where
+    Actual: fmt::Debug + PartialEq<Expected> + 'a,
+    Expected: fmt::Debug + 'a,
+{
+    Matcher::new(
+        EqDebugDiffMatcher::new(expected),
+        DiffFormat::<DebugDiff<Actual>, DebugDiff<Expected>>::new(DiffStyle::provided()),
+    )
+}
+
+/// Succeeds when the actual byte buffer equals the expected byte buffer, and shows a hex dump diff
+/// when it doesn't.
+///
+/// This works with any type that implements `AsRef<[u8]>`, like `Vec<u8>`, `&[u8]`, or `[u8; N]`.
+/// When the buffers differ, both are shown as a hex dump with 16 bytes per row. The buffers are
+/// diffed byte by byte, so a byte which was inserted or deleted leaves a gap in the other buffer
+/// instead of shifting every row after it, and the bytes which differ are highlighted. Runs of
+/// identical rows in large buffers are collapsed.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{expect, eq_bytes};
+///
+/// let packet: Vec<u8> = vec![0xca, 0xfe, 0x00, 0x11];
+///
+/// expect!(packet).to(eq_bytes([0xca, 0xfe, 0x00, 0x11]));
+/// ```
+pub fn eq_bytes<'a, Actual, Expected>(expected: Expected) -> Matcher<'a, Actual, Actual>
+where
+    Actual: fmt::Debug + AsRef<[u8]> + 'a,
+    Expected: fmt::Debug + AsRef<[u8]> + 'a,
+{
+    Matcher::new(
+        EqBytesMatcher::new(expected),
+        DiffFormat::<BytesDiff<Actual>, BytesDiff<Expected>>::new(DiffStyle::provided()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use super::eq_diff;
+    use super::{
+        eq_bytes, eq_debug_diff, eq_diff, eq_diff_with, fold_rows, fold_string,
+        pair_replaced_lines, side_by_side_rows, Row, SideBySideRow,
+    };
     use crate::expect;
+    use crate::matchers::diff::{
+        DiffAlgorithm, DiffGranularity, DiffOptions, DiffSegment, DiffTag, Diffable,
+    };
+
+    fn line_options() -> DiffOptions {
+        DiffOptions {
+            granularity: DiffGranularity::Line,
+            algorithm: DiffAlgorithm::Myers,
+        }
+    }
 
     #[test]
     fn succeeds_when_equal() {
@@ -465,4 +1305,228 @@ mod tests {
         expect!("some string").to(eq_diff("a different string"));
         expect!(["some", "slice"]).to(eq_diff(["different", "slice"]));
     }
+
+    #[test]
+    fn succeeds_when_equal_with_options() {
+        expect!("some\nstring").to(eq_diff_with("some\nstring", line_options()));
+    }
+
+    #[test]
+    fn succeeds_when_not_equal_with_options() {
+        expect!("some\nstring").to_not(eq_diff_with("some\ndifferent string", line_options()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_equal_with_options() {
+        expect!("some\nstring").to_not(eq_diff_with("some\nstring", line_options()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_not_equal_with_options() {
+        expect!("some\nstring").to(eq_diff_with("some\ndifferent string", line_options()));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Person {
+        name: &'static str,
+        id: u32,
+    }
+
+    #[test]
+    fn succeeds_when_debug_equal() {
+        expect!(Person {
+            name: "Kim",
+            id: 57
+        })
+        .to(eq_debug_diff(Person {
+            name: "Kim",
+            id: 57,
+        }));
+    }
+
+    #[test]
+    fn succeeds_when_not_debug_equal() {
+        expect!(Person {
+            name: "Kim",
+            id: 57
+        })
+        .to_not(eq_debug_diff(Person {
+            name: "Kim",
+            id: 41,
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_debug_equal() {
+        expect!(Person {
+            name: "Kim",
+            id: 57
+        })
+        .to_not(eq_debug_diff(Person {
+            name: "Kim",
+            id: 57,
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_not_debug_equal() {
+        expect!(Person {
+            name: "Kim",
+            id: 57
+        })
+        .to(eq_debug_diff(Person {
+            name: "Kim",
+            id: 41,
+        }));
+    }
+
+    #[test]
+    fn succeeds_when_bytes_equal() {
+        expect!(vec![0xcau8, 0xfe]).to(eq_bytes([0xca, 0xfe]));
+    }
+
+    #[test]
+    fn succeeds_when_bytes_not_equal() {
+        expect!(vec![0xcau8, 0xfe]).to_not(eq_bytes([0xca, 0xff]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_bytes_equal() {
+        expect!(vec![0xcau8, 0xfe]).to_not(eq_bytes([0xca, 0xfe]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_bytes_not_equal() {
+        expect!(vec![0xcau8, 0xfe]).to(eq_bytes([0xca, 0xff]));
+    }
+
+    #[test]
+    fn folds_unchanged_rows_outside_context() {
+        let diff = (0..10)
+            .map(|i| DiffSegment {
+                value: i.to_string(),
+                tag: if i == 5 {
+                    DiffTag::Delete
+                } else {
+                    DiffTag::Equal
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let rows = fold_rows(&diff, Some(1), true);
+
+        assert_eq!(rows.len(), 6);
+        assert_eq!(rows[0], Row::Folded(4));
+        assert!(matches!(&rows[1], Row::Header(hunk) if hunk.to_string() == "@@ -5,3 +5,2 @@"));
+        assert_eq!(
+            rows[2..5],
+            [Row::Segment(4), Row::Segment(5), Row::Segment(6)]
+        );
+        assert_eq!(rows[5], Row::Folded(3));
+    }
+
+    #[test]
+    fn does_not_count_missing_newline_marker_in_hunk_header() {
+        let diff = "a\nb\nc\nd\ne\nf".diff_with("a\nb\nc\nd\ne\nf\n", &line_options());
+
+        for context in [0, 1] {
+            let rows = fold_rows(&diff, Some(context), true);
+            let header = rows.iter().find_map(|row| match row {
+                Row::Header(hunk) => Some(hunk.to_string()),
+                _ => None,
+            });
+
+            let expected = if context == 0 {
+                "@@ -6,1 +6,1 @@"
+            } else {
+                "@@ -5,2 +5,2 @@"
+            };
+
+            assert_eq!(header.as_deref(), Some(expected));
+            assert_eq!(rows.last(), Some(&Row::Segment(diff.len() - 1)));
+        }
+    }
+
+    #[test]
+    fn does_not_fold_rows_without_changes() {
+        let diff = vec![DiffSegment {
+            value: String::from("same"),
+            tag: DiffTag::Equal,
+        }];
+
+        assert_eq!(fold_rows(&diff, Some(0), true), [Row::Segment(0)]);
+    }
+
+    #[test]
+    fn folds_unchanged_lines_in_strings() {
+        let folded = fold_string("a\n1\n2\n3\n4\nb", 1, true, true).unwrap();
+
+        assert_eq!(folded.front.as_deref(), Some("a\n1"));
+        assert_eq!(folded.folded, 2);
+        assert_eq!(folded.back.as_deref(), Some("4\nb"));
+
+        assert_eq!(fold_string("a\n1\n2\nb", 1, true, true), None);
+    }
+
+    #[test]
+    fn does_not_fold_a_single_unchanged_row() {
+        // With a context of 1, the changes at 2 and 6 leave one unchanged row at the start, one
+        // between them, and one at the end.
+        let diff = (0..9)
+            .map(|i| DiffSegment {
+                value: i.to_string(),
+                tag: if i == 2 || i == 6 {
+                    DiffTag::Delete
+                } else {
+                    DiffTag::Equal
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let rows = fold_rows(&diff, Some(1), false);
+
+        assert_eq!(rows, (0..9).map(Row::Segment).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn does_not_fold_a_single_unchanged_line_in_strings() {
+        assert_eq!(fold_string("a\n1\n2\n3\nb", 1, true, true), None);
+    }
+
+    #[test]
+    fn aligns_replaced_rows_side_by_side() {
+        let segment = |value: &str, tag| DiffSegment {
+            value: String::from(value),
+            tag,
+        };
+
+        let diff = vec![
+            segment("a", DiffTag::Equal),
+            segment("b", DiffTag::Delete),
+            segment("c", DiffTag::Delete),
+            segment("B", DiffTag::Insert),
+            segment("d", DiffTag::Insert),
+        ];
+
+        let rows = side_by_side_rows(
+            &diff,
+            &pair_replaced_lines(&diff),
+            fold_rows(&diff, None, false),
+        );
+
+        assert_eq!(
+            rows,
+            [
+                SideBySideRow::Pair(Some(0), Some(0)),
+                SideBySideRow::Pair(Some(1), Some(3)),
+                SideBySideRow::Pair(Some(2), Some(4)),
+            ]
+        );
+    }
 }
@@ -1,10 +1,59 @@
-use crate::core::style::{ALL_OK_HEADER, AT_LESAT_ONE_OK_HEADER};
-use crate::core::{strings, style, Format, FormattedOutput, Formatter, Matcher};
+use crate::core::{
+    strings, style, Format, FormattedFailure, FormattedOutput, Formatter, MatchOutcome, Matcher,
+};
 use crate::matchers::combinators::{CombinatorContext, CombinatorMatcher, CombinatorMode};
-use crate::matchers::SomeFailures;
+use crate::matchers::{SomeFailures, SomeOutcomes};
 
 use super::HeaderFormat;
 
+fn fmt_outcomes(
+    f: &mut Formatter,
+    outcomes: Vec<MatchOutcome<Option<FormattedFailure>, FormattedFailure>>,
+    verbose: bool,
+) {
+    let num_outcomes = outcomes.len();
+    let output_indent =
+        strings::whitespace((strings::int_len(num_outcomes, 10) + style::indent_len(1)) as usize);
+
+    for (i, outcome) in outcomes.into_iter().enumerate() {
+        if outcome.is_success() && !verbose {
+            continue;
+        }
+
+        f.set_style(style::index());
+        f.write_str(format!(
+            "{}[{}]  ",
+            strings::pad_int(i, num_outcomes, 10),
+            i,
+        ));
+        f.reset_style();
+
+        let output = match outcome {
+            MatchOutcome::Success(description) => {
+                f.set_style(style::success());
+                f.write_str(style::ok_msg());
+                f.reset_style();
+
+                description
+            }
+            MatchOutcome::Fail(fail) => {
+                f.set_style(style::failure());
+                f.write_str(style::failed_msg());
+                f.reset_style();
+
+                Some(fail)
+            }
+        };
+
+        f.write_char('\n');
+
+        if let Some(output) = output {
+            f.write_fmt(FormattedOutput::from(output).indented(output_indent.as_ref()));
+            f.write_char('\n');
+        }
+    }
+}
+
 /// A formatter for [`SomeFailures`] values.
 ///
 /// This formatter just writes the pre-formatted [`FormattedFailure`] values via
@@ -13,17 +62,35 @@ use super::HeaderFormat;
 ///
 /// If you only need to print a single [`FormattedFailure`], use [`FailureFormat`].
 ///
+/// By default, only the matchers which failed are printed. In verbose mode, the matchers which
+/// succeeded are printed too and marked as `OK`. Verbose mode is enabled globally with
+/// [`Config::verbose`], or for a single formatter with [`verbose`].
+///
 /// [`SomeFailures`]: crate::matchers::SomeFailures
 /// [`FormattedFailure`]: crate::core::FormattedFailure
 /// [`FailureFormat`]: crate::format::FailureFormat
+/// [`Config::verbose`]: crate::core::Config::verbose
+/// [`verbose`]: crate::format::SomeFailuresFormat::verbose
 #[non_exhaustive]
 #[derive(Debug, Default)]
-pub struct SomeFailuresFormat;
+pub struct SomeFailuresFormat {
+    verbose: Option<bool>,
+}
 
 impl SomeFailuresFormat {
     /// Create a new [`SomeFailuresFormat`].
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Whether to also print the matchers which succeeded.
+    ///
+    /// This overrides [`Config::verbose`] for this formatter.
+    ///
+    /// [`Config::verbose`]: crate::core::Config::verbose
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
     }
 }
 
@@ -31,30 +98,64 @@ impl Format for SomeFailuresFormat {
     type Value = SomeFailures;
 
     fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
-        let num_failures = value.len();
-        let failure_indent =
-            strings::whitespace((strings::int_len(num_failures, 10) + style::INDENT_LEN) as usize);
-
-        for (i, maybe_fail) in value.into_iter().enumerate() {
-            if let Some(fail) = maybe_fail {
-                f.set_style(style::index());
-                f.write_str(&format!(
-                    "{}[{}]  ",
-                    strings::pad_int(i, num_failures, 10),
-                    i,
-                ));
-                f.reset_style();
+        let outcomes = value
+            .into_iter()
+            .map(|maybe_fail| match maybe_fail {
+                Some(fail) => MatchOutcome::Fail(fail),
+                None => MatchOutcome::Success(None),
+            })
+            .collect();
 
-                f.set_style(style::failure());
-                f.write_str(style::FAILED_MSG);
-                f.reset_style();
-                f.write_char('\n');
+        fmt_outcomes(f, outcomes, self.verbose.unwrap_or_else(style::verbose));
 
-                f.write_fmt(FormattedOutput::from(fail).indented(failure_indent.as_ref()));
+        Ok(())
+    }
+}
 
-                f.write_char('\n');
-            };
-        }
+/// A formatter for [`SomeOutcomes`] values.
+///
+/// This is the same as [`SomeFailuresFormat`], except in verbose mode, each matcher which
+/// succeeded is printed along with its description, if it has one. This shows what the branches of
+/// an [`any`] which nearly matched were.
+///
+/// Verbose mode is enabled globally with [`Config::verbose`], or for a single formatter with
+/// [`verbose`]. The matcher must be in verbose mode too for it to record descriptions; see
+/// [`CombinatorMatcher::verbose`].
+///
+/// [`SomeOutcomes`]: crate::matchers::SomeOutcomes
+/// [`SomeFailuresFormat`]: crate::format::SomeFailuresFormat
+/// [`any`]: crate::any
+/// [`Config::verbose`]: crate::core::Config::verbose
+/// [`verbose`]: crate::format::SomeOutcomesFormat::verbose
+/// [`CombinatorMatcher::verbose`]: crate::matchers::combinators::CombinatorMatcher::verbose
+#[non_exhaustive]
+#[derive(Debug, Default)]
+pub struct SomeOutcomesFormat {
+    verbose: Option<bool>,
+}
+
+impl SomeOutcomesFormat {
+    /// Create a new [`SomeOutcomesFormat`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to also print the matchers which succeeded.
+    ///
+    /// This overrides [`Config::verbose`] for this formatter.
+    ///
+    /// [`Config::verbose`]: crate::core::Config::verbose
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
+    }
+}
+
+impl Format for SomeOutcomesFormat {
+    type Value = SomeOutcomes;
+
+    fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
+        fmt_outcomes(f, value, self.verbose.unwrap_or_else(style::verbose));
 
         Ok(())
     }
@@ -82,26 +183,26 @@ impl Format for SomeFailuresFormat {
 /// Passing the value to matchers by reference:
 ///
 /// ```
-/// use xpct::{each, expect, have_len, match_regex};
+/// use xpct::{each, expect, have_len, have_prefix};
 ///
 /// expect!("11b72db5-ff70-40a5-8728-937faf86ce48").to(each(|ctx| {
 ///     ctx.borrow::<str>()
 ///         .to(have_len(36))
-///         .to(match_regex("[0-9a-f-]+"));
+///         .to(have_prefix("11b72db5"));
 /// }));
 /// ```
 ///
 /// Passing the value to matchers by value via [`Clone`]:
 ///
 /// ```
-/// use xpct::{each, expect, have_len, match_regex};
+/// use xpct::{each, expect, have_len, have_prefix};
 ///
 /// let uuid = String::from("11b72db5-ff70-40a5-8728-937faf86ce48");
 ///
 /// expect!(uuid).to(each(|ctx| {
 ///     ctx.cloned()
 ///         .to(have_len(36))
-///         .to(match_regex("[0-9a-f-]+"));
+///         .to(have_prefix("11b72db5"));
 /// }));
 /// ```
 ///
@@ -122,17 +223,168 @@ where
     Matcher::transform(
         CombinatorMatcher::new(CombinatorMode::All, block),
         HeaderFormat::new(
-            SomeFailuresFormat::new(),
-            ALL_OK_HEADER,
-            AT_LESAT_ONE_OK_HEADER,
+            SomeOutcomesFormat::new(),
+            style::all_ok_header(),
+            style::any_ok_header(),
+        ),
+    )
+}
+
+/// Same as [`each`], but always in verbose mode.
+///
+/// When this matcher fails, it prints every matcher passed to it, marking each one as OK or FAILED,
+/// and describes the matchers which succeeded if they implement [`Match::describe`]. This does the
+/// same thing as enabling [`Config::verbose`], but only for this assertion.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{each_verbose, expect, have_len, have_prefix};
+///
+/// expect!("11b72db5-ff70-40a5-8728-937faf86ce48").to(each_verbose(|ctx| {
+///     ctx.borrow::<str>()
+///         .to(have_len(36))
+///         .to(have_prefix("11b72db5"));
+/// }));
+/// ```
+///
+/// [`Match::describe`]: crate::core::Match::describe
+/// [`Config::verbose`]: crate::Config::verbose
+pub fn each_verbose<'a, T>(block: impl FnOnce(&mut CombinatorContext<T>) + 'a) -> Matcher<'a, T, T>
+where
+    T: 'a,
+{
+    Matcher::transform(
+        CombinatorMatcher::new(CombinatorMode::All, block).verbose(true),
+        HeaderFormat::new(
+            SomeOutcomesFormat::new().verbose(true),
+            style::all_ok_header(),
+            style::any_ok_header(),
         ),
     )
 }
 
 #[cfg(test)]
 mod tests {
-    use super::each;
-    use crate::{be_gt, be_lt, expect};
+    use super::{each, SomeFailuresFormat, SomeOutcomesFormat};
+    use crate::core::{
+        FormattedFailure, FormattedOutput, Match, MatchFailure, MatchOutcome, Matcher,
+        PlainRenderer, TransformMatch,
+    };
+    use crate::format::{ExpectationFormat, MessageFormat};
+    use crate::matchers::combinators::{CombinatorContext, CombinatorMatcher, CombinatorMode};
+    use crate::matchers::{Expectation, SomeFailures, SomeOutcomes};
+    use crate::{be_gt, be_lt, expect, why};
+
+    fn failures() -> SomeFailures {
+        let fail = FormattedFailure::new(
+            MatchFailure::<(), ()>::Pos(()),
+            MessageFormat::new("Expected a failure.", "Expected no failure."),
+        )
+        .unwrap();
+
+        vec![None, Some(fail), None]
+    }
+
+    fn render(format: SomeFailuresFormat) -> String {
+        FormattedOutput::new(failures(), format)
+            .unwrap()
+            .render(&PlainRenderer)
+    }
+
+    #[test]
+    fn only_prints_failures_by_default() {
+        assert_eq!(
+            render(SomeFailuresFormat::new().verbose(false)),
+            "[1]  FAILED\n     Expected a failure.\n\n",
+        );
+    }
+
+    #[test]
+    fn verbose_prints_successes_too() {
+        assert_eq!(
+            render(SomeFailuresFormat::new().verbose(true)),
+            "[0]  OK\n[1]  FAILED\n     Expected a failure.\n\n[2]  OK\n",
+        );
+    }
+
+    /// A matcher which describes itself when it succeeds.
+    struct BePositiveMatcher;
+
+    impl Match<i32> for BePositiveMatcher {
+        type Fail = Expectation<i32>;
+
+        fn matches(&mut self, actual: &i32) -> crate::Result<bool> {
+            Ok(*actual > 0)
+        }
+
+        fn fail(self, actual: i32) -> Self::Fail {
+            Expectation { actual }
+        }
+
+        fn describe(self, actual: i32) -> Option<Self::Fail> {
+            Some(Expectation { actual })
+        }
+    }
+
+    fn be_positive<'a>() -> Matcher<'a, i32, i32> {
+        Matcher::new(
+            BePositiveMatcher,
+            ExpectationFormat::new("to be positive", "to not be positive"),
+        )
+    }
+
+    fn outcomes(verbose: bool) -> SomeOutcomes {
+        let matcher =
+            CombinatorMatcher::new(CombinatorMode::All, |ctx: &mut CombinatorContext<_>| {
+                ctx.copied()
+                    .to(be_positive())
+                    .to(be_lt(3))
+                    .to(why(be_positive(), "it's positive"))
+                    .to(be_gt(1));
+            });
+
+        match matcher.verbose(verbose).match_pos(5) {
+            Ok(MatchOutcome::Fail(outcomes)) => outcomes,
+            _ => panic!("expected the matcher to fail"),
+        }
+    }
+
+    fn render_outcomes(format: SomeOutcomesFormat, verbose: bool) -> String {
+        FormattedOutput::new(outcomes(verbose), format)
+            .unwrap()
+            .render(&PlainRenderer)
+    }
+
+    #[test]
+    fn verbose_describes_matchers_which_succeeded() {
+        assert_eq!(
+            render_outcomes(SomeOutcomesFormat::new().verbose(true), true),
+            "[0]  OK\n     Expected:\n         5\n     to be positive\n\n\
+             [1]  FAILED\n     Expected:\n         5\n     to be less than:\n         3\n\n\
+             [2]  OK\n     [why] it's positive\n     Expected:\n         5\n     to be positive\n\n\
+             [3]  OK\n",
+        );
+    }
+
+    #[test]
+    fn only_verbose_matchers_describe_themselves() {
+        assert!(matches!(outcomes(false)[0], MatchOutcome::Success(None)));
+        assert!(matches!(outcomes(true)[0], MatchOutcome::Success(Some(_))));
+    }
+
+    #[test]
+    fn matchers_do_not_describe_themselves_by_default() {
+        assert!(matches!(outcomes(true)[3], MatchOutcome::Success(None)));
+    }
+
+    #[test]
+    fn prints_only_failed_outcomes_by_default() {
+        assert_eq!(
+            render_outcomes(SomeOutcomesFormat::new().verbose(false), true),
+            "[1]  FAILED\n     Expected:\n         5\n     to be less than:\n         3\n\n",
+        );
+    }
 
     #[test]
     fn succeeds_when_all_matchers_succeed() {
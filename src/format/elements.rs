@@ -57,8 +57,8 @@ where
         MatchElementsMatcher::new(matchers),
         HeaderFormat::new(
             SomeFailuresFormat::new(),
-            style::ALL_OK_HEADER,
-            style::AT_LESAT_ONE_OK_HEADER,
+            style::all_ok_header(),
+            style::any_ok_header(),
         ),
     )
 }
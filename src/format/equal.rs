@@ -1,10 +1,25 @@
 use std::fmt;
 use std::marker::PhantomData;
 
-use crate::core::{style, Format, Formatter, MatchFailure, Matcher};
+use crate::core::{
+    max_value_len, message, style, truncate_debug_pair, DebugValue, Format, Formatter,
+    MatchFailure, Matcher, Message, ValueFormat,
+};
 use crate::matchers::equal::EqualMatcher;
 use crate::matchers::Mismatch;
 
+#[cfg(feature = "diff")]
+use {
+    super::diff::{DiffFormat, DiffStyle},
+    crate::matchers::diff::{DiffGranularity, DiffOptions, Diffable},
+};
+
+/// The number of lines of pretty-printed [`Debug`] output above which [`equal`] shows a diff.
+///
+/// [`Debug`]: std::fmt::Debug
+#[cfg(feature = "diff")]
+const DIFF_THRESHOLD_LINES: usize = 4;
+
 /// A formatter for [`Mismatch`] values.
 ///
 /// # Examples
@@ -25,10 +40,14 @@ use crate::matchers::Mismatch;
 /// );
 /// ```
 #[derive(Debug)]
-pub struct MismatchFormat<Actual, Expected> {
+pub struct MismatchFormat<Actual, Expected, Values = DebugValue> {
     marker: PhantomData<(Actual, Expected)>,
-    pos_msg: String,
-    neg_msg: String,
+    pos_msg: Message,
+    neg_msg: Message,
+    max_len: Option<Option<usize>>,
+    #[cfg(feature = "diff")]
+    diff_threshold: Option<usize>,
+    values: Values,
 }
 
 impl<Actual, Expected> MismatchFormat<Actual, Expected> {
@@ -37,46 +56,167 @@ impl<Actual, Expected> MismatchFormat<Actual, Expected> {
     /// This accepts two error messages: the one to use in the *positive* case (when we were
     /// expecting the matcher to succeed) and the one to use in the *negative* case (when we were
     /// expecting the matcher to fail).
-    pub fn new(pos_msg: impl Into<String>, neg_msg: impl Into<String>) -> Self {
+    pub fn new(pos_msg: impl Into<Message>, neg_msg: impl Into<Message>) -> Self {
         Self {
             marker: PhantomData,
             pos_msg: pos_msg.into(),
             neg_msg: neg_msg.into(),
+            max_len: None,
+            #[cfg(feature = "diff")]
+            diff_threshold: None,
+            values: DebugValue,
+        }
+    }
+}
+
+impl<Actual, Expected, Values> MismatchFormat<Actual, Expected, Values> {
+    /// Render the values with the given [`ValueFormat`] instead of their [`Debug`] implementation.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    pub fn with_values<NewValues>(
+        self,
+        values: NewValues,
+    ) -> MismatchFormat<Actual, Expected, NewValues>
+    where
+        NewValues: ValueFormat<Actual> + ValueFormat<Expected>,
+    {
+        MismatchFormat {
+            marker: PhantomData,
+            pos_msg: self.pos_msg,
+            neg_msg: self.neg_msg,
+            max_len: self.max_len,
+            #[cfg(feature = "diff")]
+            diff_threshold: self.diff_threshold,
+            values,
         }
     }
+
+    /// Set the maximum length of the values in the output, in characters.
+    ///
+    /// Values longer than this have their middle elided. When both values are strings, they keep a
+    /// window around the first position where they differ. Pass `None` to never truncate values.
+    ///
+    /// This overrides the global limit set with [`set_max_value_len`]. It also applies when this
+    /// format would show a diff; see [`diff_when_longer_than`].
+    ///
+    /// The provided matchers, like [`equal`], don't expose this, so they always use the global
+    /// limit. To set a limit for a single assertion, create the matcher yourself with a format that
+    /// sets one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xpct::core::Matcher;
+    /// use xpct::format::MismatchFormat;
+    /// use xpct::matchers::equal::EqualMatcher;
+    /// use xpct::expect;
+    ///
+    /// let format = MismatchFormat::new("to equal", "to not equal").max_len(Some(80));
+    /// let matcher = Matcher::new(EqualMatcher::new(vec![0u8; 1000]), format);
+    ///
+    /// expect!(vec![0u8; 1000]).to(matcher);
+    /// ```
+    ///
+    /// [`set_max_value_len`]: crate::core::set_max_value_len
+    /// [`diff_when_longer_than`]: crate::format::MismatchFormat::diff_when_longer_than
+    pub fn max_len(mut self, len: Option<usize>) -> Self {
+        self.max_len = Some(len);
+        self
+    }
+
+    /// Show a diff instead when the values are too long to compare by eye.
+    ///
+    /// If the pretty-printed output of either value is longer than `lines` lines, this formats the
+    /// failure as a line diff of their pretty-printed output using [`DiffFormat`], like
+    /// [`eq_debug_diff`] does. Values are pretty-printed with [`ValueFormat::fmt_value_pretty`],
+    /// which uses `{:#?}` by default.
+    ///
+    /// Values whose pretty-printed output is longer than the maximum length set with
+    /// [`max_len`] are never diffed. They're shown truncated instead, since diffing them would be
+    /// slow and the diff would be as long as the values themselves.
+    ///
+    /// [`max_len`]: crate::format::MismatchFormat::max_len
+    /// [`DiffFormat`]: crate::format::DiffFormat
+    /// [`eq_debug_diff`]: crate::eq_debug_diff
+    #[cfg(feature = "diff")]
+    pub fn diff_when_longer_than(mut self, lines: usize) -> Self {
+        self.diff_threshold = Some(lines);
+        self
+    }
 }
 
-impl<Actual, Expected> Format for MismatchFormat<Actual, Expected>
+impl<Actual, Expected, Values> Format for MismatchFormat<Actual, Expected, Values>
 where
-    Actual: fmt::Debug,
-    Expected: fmt::Debug,
+    Values: ValueFormat<Actual> + ValueFormat<Expected>,
 {
     type Value = MatchFailure<Mismatch<Actual, Expected>>;
 
     fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
+        let max_len = self.max_len.unwrap_or_else(max_value_len);
+
+        #[cfg(feature = "diff")]
+        if let Some(threshold) = self.diff_threshold {
+            let mismatch = value.unwrap();
+
+            let expected =
+                ValueFormat::<Actual>::fmt_value_pretty(&self.values, &mismatch.expected);
+            let actual = ValueFormat::<Expected>::fmt_value_pretty(&self.values, &mismatch.actual);
+
+            let is_long =
+                expected.lines().count() > threshold || actual.lines().count() > threshold;
+
+            // Diffing values which are too long to show in full would be slow, and the diff would
+            // be just as long, so we show them truncated instead.
+            let fits = max_len.map_or(true, |max_len| {
+                expected.chars().count() <= max_len && actual.chars().count() <= max_len
+            });
+
+            if is_long && fits {
+                let options = DiffOptions {
+                    granularity: DiffGranularity::Line,
+                    ..Default::default()
+                };
+
+                let diff = expected.diff_with(actual, &options);
+                let diff_value = if value.is_pos() {
+                    MatchFailure::Pos(diff)
+                } else {
+                    MatchFailure::Neg(diff)
+                };
+
+                return DiffFormat::<String, String>::with_options(DiffStyle::provided(), options)
+                    .fmt(f, diff_value);
+            }
+        }
+
         let mismatch = value.unwrap();
 
+        let actual = ValueFormat::<Expected>::fmt_value(&self.values, &mismatch.actual);
+        let expected = ValueFormat::<Actual>::fmt_value(&self.values, &mismatch.expected);
+        let (actual, expected) = truncate_debug_pair(&actual, &expected, max_len);
+
         f.set_style(style::important());
-        f.write_str("Expected:\n");
+        f.write_str(message("label.expected"));
+        f.write_char('\n');
 
         f.set_style(style::bad());
         f.indented(style::indent(1), |f| {
-            f.write_str(format!("{:?}", mismatch.actual));
+            f.write_str(actual);
             Ok(())
         })?;
         f.write_char('\n');
 
         f.set_style(style::important());
         if value.is_pos() {
-            f.write_str(&self.pos_msg);
+            f.write_str(self.pos_msg.resolve());
         } else {
-            f.write_str(&self.neg_msg);
+            f.write_str(self.neg_msg.resolve());
         }
         f.write_str(":\n");
 
         f.set_style(style::bad());
         f.indented(style::indent(1), |f| {
-            f.write_str(format!("{:?}", mismatch.expected));
+            f.write_str(expected);
             Ok(())
         })?;
         f.write_char('\n');
@@ -87,6 +227,15 @@ where
 
 /// Succeeds when the actual value equals the expected value.
 ///
+/// With the `diff` Cargo feature enabled, if either value is more than a few lines long when
+/// pretty-printed with `{:#?}`, this shows a line diff of the two values instead of printing both
+/// in full, like [`eq_debug_diff`]. Otherwise, it shows the expected and actual values. This is
+/// decided by the length of the [`Debug`] output alone; the values are diffed as text even if they
+/// implement [`Diffable`]. Values too long to show in full are never diffed; they're truncated
+/// like any other value. See [`set_max_value_len`].
+///
+/// To always show a diff, use [`eq_diff`] or [`eq_debug_diff`] instead.
+///
 /// # Examples
 ///
 /// ```
@@ -94,21 +243,46 @@ where
 ///
 /// expect!("foobar").to(equal("foobar"));
 /// ```
+///
+/// [`eq_diff`]: crate::eq_diff
+/// [`eq_debug_diff`]: crate::eq_debug_diff
+/// [`Debug`]: std::fmt::Debug
+/// [`Diffable`]: crate::matchers::diff::Diffable
+/// [`set_max_value_len`]: crate::core::set_max_value_len
 pub fn equal<'a, Actual, Expected>(expected: Expected) -> Matcher<'a, Actual, Actual>
 where
     Actual: fmt::Debug + PartialEq<Expected> + Eq + 'a,
     Expected: fmt::Debug + 'a,
 {
-    Matcher::new(
-        EqualMatcher::new(expected),
-        MismatchFormat::new("to equal", "to not equal"),
-    )
+    let format = MismatchFormat::new(Message::id("equal.pos"), Message::id("equal.neg"));
+
+    #[cfg(feature = "diff")]
+    let format = format.diff_when_longer_than(DIFF_THRESHOLD_LINES);
+
+    Matcher::new(EqualMatcher::new(expected), format)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::equal;
+    use super::{equal, MismatchFormat};
+    use crate::core::{FormattedOutput, MatchFailure};
     use crate::expect;
+    use crate::matchers::Mismatch;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Person {
+        name: &'static str,
+        id: u32,
+        roles: Vec<&'static str>,
+    }
+
+    fn person(id: u32) -> Person {
+        Person {
+            name: "Kim",
+            id,
+            roles: vec!["lieutenant", "detective"],
+        }
+    }
 
     #[test]
     fn succeeds_when_equal() {
@@ -131,4 +305,81 @@ mod tests {
     fn fails_when_not_equal() {
         expect!("some string").to(equal("a different string"));
     }
+
+    #[test]
+    fn succeeds_when_long_values_equal() {
+        expect!(person(57)).to(equal(person(57)));
+    }
+
+    #[test]
+    fn succeeds_when_long_values_not_equal() {
+        expect!(person(57)).to_not(equal(person(41)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_long_values_equal() {
+        expect!(person(57)).to_not(equal(person(57)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_long_values_not_equal() {
+        expect!(person(57)).to(equal(person(41)));
+    }
+
+    fn format_mismatch<T>(expected: T, actual: T) -> String
+    where
+        T: std::fmt::Debug,
+    {
+        let format = MismatchFormat::new("to equal", "to not equal");
+
+        #[cfg(feature = "diff")]
+        let format = format.diff_when_longer_than(super::DIFF_THRESHOLD_LINES);
+
+        FormattedOutput::new(MatchFailure::Pos(Mismatch { expected, actual }), format)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    #[cfg(feature = "diff")]
+    fn diffs_long_values() {
+        let output = format_mismatch(person(57), person(41));
+
+        assert!(output.contains("@@"));
+    }
+
+    #[test]
+    fn truncates_instead_of_diffing_huge_values() {
+        let expected = (0..50_000).collect::<Vec<_>>();
+        let mut actual = expected.clone();
+        actual[25_000] = 0;
+
+        let output = format_mismatch(expected, actual);
+
+        assert!(!output.contains("@@"));
+        assert!(output.contains("more elements"));
+        assert!(output.lines().count() < 10);
+    }
+
+    #[test]
+    fn truncates_with_max_len_instead_of_diffing() {
+        let format = MismatchFormat::new("to equal", "to not equal").max_len(Some(20));
+
+        #[cfg(feature = "diff")]
+        let format = format.diff_when_longer_than(super::DIFF_THRESHOLD_LINES);
+
+        let mismatch = Mismatch {
+            expected: person(57),
+            actual: person(41),
+        };
+
+        let output = FormattedOutput::new(MatchFailure::Pos(mismatch), format)
+            .unwrap()
+            .to_string();
+
+        assert!(!output.contains("@@"));
+        assert!(output.contains("more field"));
+    }
 }
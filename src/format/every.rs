@@ -1,7 +1,6 @@
 use std::fmt;
 
-use crate::core::style::{ALL_OK_HEADER, AT_LESAT_ONE_OK_HEADER};
-use crate::core::Matcher;
+use crate::core::{style, Matcher};
 use crate::matchers::collections::EveryMatcher;
 
 use super::{HeaderFormat, SomeFailuresFormat};
@@ -43,8 +42,8 @@ where
         EveryMatcher::new(matcher),
         HeaderFormat::new(
             SomeFailuresFormat::new(),
-            ALL_OK_HEADER,
-            AT_LESAT_ONE_OK_HEADER,
+            style::all_ok_header(),
+            style::any_ok_header(),
         ),
     )
 }
@@ -1,6 +1,5 @@
 use std::any::type_name;
 
-use crate::core::style::{ALL_FIELDS_OK_HEADER, AT_LESAT_ONE_FIELD_OK_HEADER};
 use crate::core::{style, Format, FormattedOutput, Formatter, Matcher};
 use crate::matchers::combinators::CombinatorMode;
 use crate::matchers::fields::{FieldMatcher, FieldsSpec};
@@ -44,13 +43,13 @@ impl Format for ByFieldFormat {
 
             if let Some(fail) = maybe_fail {
                 f.set_style(style::failure());
-                f.write_str(style::FAILED_MSG);
+                f.write_str(style::failed_msg());
                 f.reset_style();
                 f.write_char('\n');
                 f.write_fmt(FormattedOutput::from(fail).indented(style::indent(2)));
             } else {
                 f.set_style(style::success());
-                f.write_str(style::OK_MSG);
+                f.write_str(style::ok_msg());
                 f.reset_style();
                 f.write_char('\n');
             }
@@ -109,8 +108,8 @@ where
         FieldMatcher::new(CombinatorMode::All, spec),
         HeaderFormat::new(
             ByFieldFormat::new(type_name::<T>()),
-            ALL_FIELDS_OK_HEADER,
-            AT_LESAT_ONE_FIELD_OK_HEADER,
+            style::all_fields_ok_header(),
+            style::any_field_ok_header(),
         ),
     )
 }
@@ -127,8 +126,8 @@ where
         FieldMatcher::new(CombinatorMode::Any, spec),
         HeaderFormat::new(
             ByFieldFormat::new(type_name::<T>()),
-            AT_LESAT_ONE_FIELD_OK_HEADER,
-            ALL_FIELDS_OK_HEADER,
+            style::any_field_ok_header(),
+            style::all_fields_ok_header(),
         ),
     )
 }
@@ -1,7 +1,7 @@
 use std::fmt;
 use std::path::Path;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::files::{FileExistsMatcher, FileExistsMode};
 
 use super::ExpectationFormat;
@@ -25,8 +25,8 @@ where
     Matcher::new(
         FileExistsMatcher::new(FileExistsMode::Exists),
         ExpectationFormat::new(
-            "to exist in the filesystem",
-            "to not exist in the filesystem",
+            Message::id("be-existing-file.pos"),
+            Message::id("be-existing-file.neg"),
         ),
     )
 }
@@ -49,7 +49,10 @@ where
 {
     Matcher::new(
         FileExistsMatcher::new(FileExistsMode::RegularFile),
-        ExpectationFormat::new("to exist and be a regular file", "to not be a regular file"),
+        ExpectationFormat::new(
+            Message::id("be-regular-file.pos"),
+            Message::id("be-regular-file.neg"),
+        ),
     )
 }
 
@@ -71,7 +74,10 @@ where
 {
     Matcher::new(
         FileExistsMatcher::new(FileExistsMode::Directory),
-        ExpectationFormat::new("to exist and be a directory", "to not be a directory"),
+        ExpectationFormat::new(
+            Message::id("be-directory.pos"),
+            Message::id("be-directory.neg"),
+        ),
     )
 }
 
@@ -91,9 +97,6 @@ where
 {
     Matcher::new(
         FileExistsMatcher::new(FileExistsMode::Symlink),
-        ExpectationFormat::new(
-            "to exist and be a symbolic link",
-            "to not be a symbolic link",
-        ),
+        ExpectationFormat::new(Message::id("be-symlink.pos"), Message::id("be-symlink.neg")),
     )
 }
@@ -1,6 +1,6 @@
 #![cfg(feature = "float")]
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::numbers::ApproxEqFloatMatcher;
 
 use super::MismatchFormat;
@@ -24,7 +24,7 @@ use super::MismatchFormat;
 pub fn approx_eq_f32<'a>(expected: f32, ulps: i32) -> Matcher<'a, f32, f32> {
     Matcher::new(
         ApproxEqFloatMatcher::<f32, i32>::new(expected, ulps),
-        MismatchFormat::new("to approximately equal", "to not approximately equal"),
+        MismatchFormat::new(Message::id("approx-eq.pos"), Message::id("approx-eq.neg")),
     )
 }
 
@@ -34,7 +34,7 @@ pub fn approx_eq_f32<'a>(expected: f32, ulps: i32) -> Matcher<'a, f32, f32> {
 pub fn approx_eq_f64<'a>(expected: f64, ulps: i64) -> Matcher<'a, f64, f64> {
     Matcher::new(
         ApproxEqFloatMatcher::<f64, i64>::new(expected, ulps),
-        MismatchFormat::new("to approximately equal", "to not approximately equal"),
+        MismatchFormat::new(Message::id("approx-eq.pos"), Message::id("approx-eq.neg")),
     )
 }
 
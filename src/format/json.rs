@@ -3,16 +3,21 @@
 use std::borrow::Cow;
 use std::fmt;
 
+use serde_json::Value as JsonValue;
+
 use crate::core::Matcher;
 use crate::matchers::strings::JsonMatcher;
 
-use super::MismatchFormat;
+use super::diff::{DiffFormat, DiffStyle};
 
 /// Succeeds when the actual string and the expected string are equivalent JSON.
 ///
 /// This allows you to compare JSON strings, ignoring whitespace and the order of keys in objects.
 /// The order of elements in arrays is still significant, though.
 ///
+/// If the JSON is not equivalent, this shows a structural diff of the two values, listing each value
+/// that differs along with its path, like `$.user.roles[2]`.
+///
 /// # Examples
 ///
 /// ```
@@ -33,7 +38,7 @@ where
 {
     Matcher::new(
         JsonMatcher::new(json),
-        MismatchFormat::new("to be equivalent JSON to", "to not be equivalent JSON to"),
+        DiffFormat::<JsonValue, JsonValue>::new(DiffStyle::provided()),
     )
 }
 
@@ -0,0 +1,171 @@
+#![cfg(feature = "json-schema")]
+
+use std::fmt;
+
+use crate::core::{message, strings, style, Format, Formatter, MatchFailure, Matcher};
+use crate::matchers::strings::{AsJson, JsonSchemaMatcher, SchemaViolation};
+
+/// A formatter for a list of [`SchemaViolation`] values.
+///
+/// This prints each error along with the path of the invalid value and the schema keyword that
+/// failed validation.
+///
+/// [`SchemaViolation`]: crate::matchers::strings::SchemaViolation
+#[non_exhaustive]
+#[derive(Debug, Default)]
+pub struct SchemaViolationsFormat;
+
+impl SchemaViolationsFormat {
+    /// Create a new [`SchemaViolationsFormat`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Format for SchemaViolationsFormat {
+    type Value = MatchFailure<Vec<SchemaViolation>>;
+
+    fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
+        if value.is_neg() {
+            f.set_style(style::bad());
+            f.write_str(message("match-json-schema.neg"));
+            f.reset_style();
+            f.write_char('\n');
+
+            return Ok(());
+        }
+
+        let violations = value.into_inner();
+        let num_violations = violations.len();
+
+        f.set_style(style::important());
+        f.write_str(message("match-json-schema.pos"));
+        f.write_char('\n');
+        f.reset_style();
+
+        // Align the message with the path, after the index.
+        let message_indent =
+            strings::whitespace((strings::int_len(num_violations, 10) + 4) as usize);
+
+        f.indented(style::indent(1), |f| {
+            for (i, violation) in violations.into_iter().enumerate() {
+                f.set_style(style::index());
+                f.write_str(format!(
+                    "{}[{}]  ",
+                    strings::pad_int(i, num_violations, 10),
+                    i,
+                ));
+                f.reset_style();
+
+                f.set_style(style::info());
+                f.write_str(&violation.instance_path);
+                f.reset_style();
+                f.write_str(format!(" ({})\n", violation.keyword));
+
+                f.set_style(style::bad());
+                f.indented(message_indent.as_ref(), |f| {
+                    f.write_str(&violation.message);
+                    Ok(())
+                })?;
+                f.reset_style();
+                f.write_char('\n');
+            }
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+}
+
+/// Succeeds when the actual value is valid according to the given JSON schema.
+///
+/// The actual value and the schema can each be either a JSON string or a [`serde_json::Value`].
+/// Schemas are interpreted as draft 2020-12.
+///
+/// This is useful when you can't know the exact values in some JSON ahead of time, but you know
+/// what shape it should have. If you do know the exact values, use [`match_json`] instead.
+///
+/// If the value is invalid, this lists every validation error along with the path of the invalid
+/// value and the schema keyword that failed validation. If the schema itself is invalid, the
+/// matcher returns an error.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{expect, match_json_schema};
+///
+/// let schema = r#"
+///     {
+///         "type": "object",
+///         "properties": {
+///             "name": { "type": "string" },
+///             "age": { "type": "integer", "minimum": 0 }
+///         },
+///         "required": ["name"]
+///     }
+/// "#;
+///
+/// expect!(r#"{"name":"Lena","age":31}"#).to(match_json_schema(schema));
+/// ```
+///
+/// [`match_json`]: crate::match_json
+pub fn match_json_schema<'a, Actual>(schema: impl AsJson + 'a) -> Matcher<'a, Actual, Actual>
+where
+    Actual: fmt::Debug + AsJson + 'a,
+{
+    Matcher::new(
+        JsonSchemaMatcher::new(schema),
+        SchemaViolationsFormat::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::match_json_schema;
+    use crate::expect;
+
+    fn schema() -> &'static str {
+        r#"
+            {
+                "type": "object",
+                "properties": {
+                    "key1": { "type": "string" },
+                    "key2": { "type": "integer" }
+                },
+                "required": ["key1"]
+            }
+        "#
+    }
+
+    #[test]
+    fn succeeds_when_matches_schema() {
+        expect!(r#"{"key1":"value1","key2":2}"#).to(match_json_schema(schema()));
+        expect!(json!({ "key1": "value1" })).to(match_json_schema(schema()));
+    }
+
+    #[test]
+    fn succeeds_when_not_matches_schema() {
+        expect!(r#"{"key2":"value2"}"#).to_not(match_json_schema(schema()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_matches_schema() {
+        expect!(r#"{"key1":"value1","key2":2}"#).to_not(match_json_schema(schema()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_not_matches_schema() {
+        expect!(r#"{"key2":"value2"}"#).to(match_json_schema(schema()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_schema_is_invalid() {
+        expect!(r#"{"key1":"value1"}"#).to(match_json_schema(r#"{"type": 1}"#));
+    }
+}
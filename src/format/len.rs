@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::collections::{BeEmptyMatcher, HaveLenMatcher, Len};
 
 use super::{ExpectationFormat, MismatchFormat};
@@ -23,7 +23,7 @@ where
 {
     Matcher::new(
         HaveLenMatcher::new(len),
-        MismatchFormat::new("to have length", "to not have length"),
+        MismatchFormat::new(Message::id("have-len.pos"), Message::id("have-len.neg")),
     )
 }
 
@@ -45,7 +45,7 @@ where
 {
     Matcher::new(
         BeEmptyMatcher::new(),
-        ExpectationFormat::new("to be empty", "to not be empty"),
+        ExpectationFormat::new(Message::id("be-empty.pos"), Message::id("be-empty.neg")),
     )
 }
 
@@ -37,6 +37,7 @@ mod fields;
 mod file;
 mod float;
 mod json;
+mod json_schema;
 mod len;
 mod map;
 mod not;
@@ -45,22 +46,26 @@ mod ord;
 mod pattern;
 mod regex;
 mod result;
+mod serialize;
 mod substr;
 mod time;
+mod toml;
 mod why;
+mod yaml;
 mod zero;
 
 /// Types for styling formatted diffs.
 #[cfg(feature = "diff")]
 pub mod diff {
     pub use super::diffing::{
-        CollectionDiffStyle, DiffFormat, DiffSegmentStyle, DiffStyle, StringDiffStyle,
+        CollectionDiffStyle, DiffFormat, DiffLayout, DiffSegmentStyle, DiffStyle, StringDiffStyle,
     };
 }
 
+pub use all::ChainFormat;
 pub use any::HeaderFormat;
 pub use boolean::MessageFormat;
-pub use each::SomeFailuresFormat;
+pub use each::{SomeFailuresFormat, SomeOutcomesFormat};
 pub use equal::MismatchFormat;
 pub use fields::ByFieldFormat;
 pub use map::InfallibleFormat;
@@ -71,13 +76,16 @@ pub use why::WhyFormat;
 #[cfg(feature = "diff")]
 pub use diffing::DiffFormat;
 
+#[cfg(feature = "json-schema")]
+pub use json_schema::SchemaViolationsFormat;
+
 pub(crate) mod matchers {
-    pub use super::all::all;
-    pub use super::any::any;
+    pub use super::all::{all, all_verbose};
+    pub use super::any::{any, any_verbose};
     pub use super::boolean::{be_false, be_true};
     pub use super::contain::{be_in, consist_of, contain_element, contain_elements};
     pub use super::default::be_default;
-    pub use super::each::each;
+    pub use super::each::{each, each_verbose};
     pub use super::elements::match_elements;
     pub use super::equal::equal;
     pub use super::every::every;
@@ -96,7 +104,7 @@ pub(crate) mod matchers {
     pub use super::zero::be_zero;
 
     #[cfg(feature = "diff")]
-    pub use super::diffing::eq_diff;
+    pub use super::diffing::{eq_bytes, eq_debug_diff, eq_diff, eq_diff_with};
 
     #[cfg(feature = "casefold")]
     pub use super::casefold::eq_casefold;
@@ -107,6 +115,18 @@ pub(crate) mod matchers {
     #[cfg(feature = "json")]
     pub use super::json::match_json;
 
+    #[cfg(feature = "json")]
+    pub use super::serialize::{deserialize_from_json, roundtrip_json, serialize_to_json};
+
+    #[cfg(feature = "json-schema")]
+    pub use super::json_schema::match_json_schema;
+
     #[cfg(feature = "regex")]
     pub use super::regex::match_regex;
+
+    #[cfg(feature = "toml")]
+    pub use super::toml::match_toml;
+
+    #[cfg(feature = "yaml")]
+    pub use super::yaml::match_yaml;
 }
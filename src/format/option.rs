@@ -2,7 +2,10 @@ use std::convert::Infallible;
 use std::fmt;
 use std::marker::PhantomData;
 
-use crate::core::{style, Format, Formatter, MatchFailure, Matcher, NegFormat};
+use crate::core::{
+    max_value_len, message, style, truncate_debug, DebugValue, Format, Formatter, MatchFailure,
+    Matcher, Message, NegFormat, ValueFormat,
+};
 use crate::matchers::option::BeSomeMatcher;
 use crate::matchers::Expectation;
 
@@ -27,10 +30,12 @@ use crate::matchers::Expectation;
 /// );
 /// ```
 #[derive(Debug)]
-pub struct ExpectationFormat<Actual> {
+pub struct ExpectationFormat<Actual, Values = DebugValue> {
     marker: PhantomData<Actual>,
-    pos_msg: String,
-    neg_msg: String,
+    pos_msg: Message,
+    neg_msg: Message,
+    max_len: Option<Option<usize>>,
+    values: Values,
 }
 
 impl<Actual> ExpectationFormat<Actual> {
@@ -39,18 +44,54 @@ impl<Actual> ExpectationFormat<Actual> {
     /// This accepts two error messages: the one to use in the *positive* case (when we were
     /// expecting the matcher to succeed) and the one to use in the *negative* case (when we were
     /// expecting the matcher to fail).
-    pub fn new(pos_msg: impl Into<String>, neg_msg: impl Into<String>) -> Self {
+    pub fn new(pos_msg: impl Into<Message>, neg_msg: impl Into<Message>) -> Self {
         Self {
             marker: PhantomData,
             pos_msg: pos_msg.into(),
             neg_msg: neg_msg.into(),
+            max_len: None,
+            values: DebugValue,
         }
     }
 }
 
-impl<Actual> Format for ExpectationFormat<Actual>
+impl<Actual, Values> ExpectationFormat<Actual, Values> {
+    /// Render the value with the given [`ValueFormat`] instead of its [`Debug`] implementation.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    pub fn with_values<NewValues>(self, values: NewValues) -> ExpectationFormat<Actual, NewValues>
+    where
+        NewValues: ValueFormat<Actual>,
+    {
+        ExpectationFormat {
+            marker: PhantomData,
+            pos_msg: self.pos_msg,
+            neg_msg: self.neg_msg,
+            max_len: self.max_len,
+            values,
+        }
+    }
+
+    /// Set the maximum length of the value in the output, in characters.
+    ///
+    /// Values longer than this have their middle elided. Pass `None` to never truncate the value.
+    ///
+    /// This overrides the global limit set with [`set_max_value_len`]. The provided matchers, like
+    /// [`be_some`], don't expose this; see [`MismatchFormat::max_len`] for how to set a limit for a
+    /// single assertion.
+    ///
+    /// [`set_max_value_len`]: crate::core::set_max_value_len
+    /// [`be_some`]: crate::be_some
+    /// [`MismatchFormat::max_len`]: crate::format::MismatchFormat::max_len
+    pub fn max_len(mut self, len: Option<usize>) -> Self {
+        self.max_len = Some(len);
+        self
+    }
+}
+
+impl<Actual, Values> Format for ExpectationFormat<Actual, Values>
 where
-    Actual: fmt::Debug,
+    Values: ValueFormat<Actual>,
 {
     type Value = MatchFailure<Expectation<Actual>>;
 
@@ -58,20 +99,24 @@ where
         let expectation = value.unwrap();
 
         f.set_style(style::important());
-        f.write_str("Expected:\n");
+        f.write_str(message("label.expected"));
+        f.write_char('\n');
 
         f.set_style(style::bad());
         f.indented(style::indent(1), |f| {
-            f.write_str(format!("{:?}", expectation.actual));
+            f.write_str(truncate_debug(
+                &self.values.fmt_value(&expectation.actual),
+                self.max_len.unwrap_or_else(max_value_len),
+            ));
             Ok(())
         })?;
         f.write_char('\n');
 
         f.set_style(style::important());
         if value.is_pos() {
-            f.write_str(&self.pos_msg);
+            f.write_str(self.pos_msg.resolve());
         } else {
-            f.write_str(&self.neg_msg);
+            f.write_str(self.neg_msg.resolve());
         }
         f.write_char('\n');
 
@@ -80,7 +125,7 @@ where
 }
 
 fn option_format<T>() -> ExpectationFormat<Option<T>> {
-    ExpectationFormat::new("to be Some(_)", "to be None")
+    ExpectationFormat::new(Message::id("be-some.pos"), Message::id("be-some.neg"))
 }
 
 /// Succeeds when the actual value is [`Some`].
@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 use std::fmt;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::ord::{BeSortedByMatcher, BeSortedMatcher, Inequality, OrdMatcher, SortOrder};
 
 use super::{ExpectationFormat, MismatchFormat};
@@ -22,7 +22,7 @@ where
 {
     Matcher::new(
         OrdMatcher::new(expected, Inequality::Greater),
-        MismatchFormat::new("to be greater than", "to not be greater than"),
+        MismatchFormat::new(Message::id("be-gt.pos"), Message::id("be-gt.neg")),
     )
 }
 
@@ -43,10 +43,7 @@ where
 {
     Matcher::new(
         OrdMatcher::new(expected, Inequality::GreaterOrEqual),
-        MismatchFormat::new(
-            "to be greater than or equal to",
-            "to not be greater than or equal to",
-        ),
+        MismatchFormat::new(Message::id("be-ge.pos"), Message::id("be-ge.neg")),
     )
 }
 
@@ -66,7 +63,7 @@ where
 {
     Matcher::new(
         OrdMatcher::new(expected, Inequality::Less),
-        MismatchFormat::new("to be less than", "to not be less than"),
+        MismatchFormat::new(Message::id("be-lt.pos"), Message::id("be-lt.neg")),
     )
 }
 
@@ -87,10 +84,7 @@ where
 {
     Matcher::new(
         OrdMatcher::new(expected, Inequality::LessOrEqual),
-        MismatchFormat::new(
-            "to be less than or equal to",
-            "to not be less than or equal to",
-        ),
+        MismatchFormat::new(Message::id("be-le.pos"), Message::id("be-le.neg")),
     )
 }
 
@@ -111,8 +105,8 @@ where
     Matcher::new(
         BeSortedMatcher::new(SortOrder::Asc),
         ExpectationFormat::new(
-            "to be sorted in ascending order",
-            "to not be sorted in ascending order",
+            Message::id("be-sorted-asc.pos"),
+            Message::id("be-sorted-asc.neg"),
         ),
     )
 }
@@ -134,8 +128,8 @@ where
     Matcher::new(
         BeSortedMatcher::new(SortOrder::Desc),
         ExpectationFormat::new(
-            "to be sorted in descending order",
-            "to not be sorted in descending order",
+            Message::id("be-sorted-desc.pos"),
+            Message::id("be-sorted-desc.neg"),
         ),
     )
 }
@@ -161,8 +155,8 @@ where
     Matcher::new(
         BeSortedByMatcher::new(predicate),
         ExpectationFormat::new(
-            "to be sorted by the given predicate",
-            "to not be sorted by the given predicate",
+            Message::id("be-sorted-by.pos"),
+            Message::id("be-sorted-by.neg"),
         ),
     )
 }
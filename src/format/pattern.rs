@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::pattern::{Pattern, PatternMatcher};
 
 use super::MismatchFormat;
@@ -59,6 +59,9 @@ where
 {
     Matcher::new(
         PatternMatcher::new(spec),
-        MismatchFormat::new("to match the pattern", "to not match the pattern"),
+        MismatchFormat::new(
+            Message::id("match-pattern.pos"),
+            Message::id("match-pattern.neg"),
+        ),
     )
 }
@@ -2,7 +2,7 @@
 
 use std::fmt;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::strings::RegexMatcher;
 
 use super::MismatchFormat;
@@ -27,7 +27,10 @@ where
 {
     Matcher::new(
         RegexMatcher::new(regex),
-        MismatchFormat::new("to match the regex", "to not match the regex"),
+        MismatchFormat::new(
+            Message::id("match-regex.pos"),
+            Message::id("match-regex.neg"),
+        ),
     )
 }
 
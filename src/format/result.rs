@@ -1,12 +1,12 @@
 use std::fmt;
 
-use crate::core::{Matcher, NegFormat};
+use crate::core::{Matcher, Message, NegFormat};
 use crate::matchers::result::BeOkMatcher;
 
 use super::ExpectationFormat;
 
 fn result_format<T, E>() -> ExpectationFormat<Result<T, E>> {
-    ExpectationFormat::new("to be Ok(_)", "to be Err(_)")
+    ExpectationFormat::new(Message::id("be-ok.pos"), Message::id("be-ok.neg"))
 }
 
 /// Succeeds when the actual value is [`Ok`].
@@ -0,0 +1,230 @@
+#![cfg(feature = "json")]
+
+use std::borrow::Cow;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::core::Matcher;
+use crate::matchers::diff::DebugDiff;
+use crate::matchers::map::TryMapMatcher;
+use crate::matchers::serialize::{RoundtripJsonMatcher, SerializeJsonMatcher};
+
+use super::diff::{DiffFormat, DiffStyle};
+use super::FailureFormat;
+
+/// Succeeds when the actual value serializes to JSON that is equivalent to the expected string.
+///
+/// Like [`match_json`], this ignores whitespace and the order of keys in objects. If the JSON is
+/// not equivalent, this shows a structural diff of the two values, listing each value that differs
+/// along with its path, like `$.user.roles[2]`.
+///
+/// This fails with an error if the expected string is not valid JSON or if the actual value can't
+/// be serialized.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use xpct::{expect, serialize_to_json};
+///
+/// #[derive(Debug, Serialize)]
+/// struct Person {
+///     name: String,
+///     id: u32,
+/// }
+///
+/// let value = Person {
+///     name: String::from("Kim Kitsuragi"),
+///     id: 57,
+/// };
+///
+/// expect!(value).to(serialize_to_json(r#"{ "id": 57, "name": "Kim Kitsuragi" }"#));
+/// ```
+///
+/// [`match_json`]: crate::match_json
+pub fn serialize_to_json<'a, Actual>(json: impl Into<Cow<'a, str>>) -> Matcher<'a, Actual, Actual>
+where
+    Actual: fmt::Debug + Serialize + 'a,
+{
+    Matcher::new(
+        SerializeJsonMatcher::new(json),
+        DiffFormat::<JsonValue, JsonValue>::new(DiffStyle::provided()),
+    )
+}
+
+/// Fallibly deserialize the input JSON string into a value of type `T`.
+///
+/// The input must be a `&str`. If you have an owned `String`, you can pass it to [`expect!`] with
+/// [`String::as_str`].
+///
+/// This matcher always succeeds as long as the string can be deserialized, even when negated.
+/// Therefore negating it has no effect.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use xpct::{deserialize_from_json, equal, expect};
+///
+/// #[derive(Debug, PartialEq, Eq, Deserialize)]
+/// struct Person {
+///     name: String,
+///     id: u32,
+/// }
+///
+/// expect!(r#"{ "name": "Kim Kitsuragi", "id": 57 }"#)
+///     .to(deserialize_from_json::<Person>())
+///     .to(equal(Person {
+///         name: String::from("Kim Kitsuragi"),
+///         id: 57,
+///     }));
+/// ```
+///
+/// [`expect!`]: crate::expect!
+pub fn deserialize_from_json<'a, T>() -> Matcher<'a, &'a str, T>
+where
+    T: DeserializeOwned + 'a,
+{
+    Matcher::transform(
+        TryMapMatcher::new(|json: &'a str| Ok(serde_json::from_str(json)?)),
+        FailureFormat::new(),
+    )
+}
+
+/// Succeeds when the actual value is unchanged after serializing it to JSON and deserializing it.
+///
+/// This compares the original value and the round-tripped value with [`PartialEq`]. If they're not
+/// equal, this shows a diff of their [`Debug`] representations, like [`eq_debug_diff`] does.
+///
+/// This fails with an error if the value can't be serialized or deserialized.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use xpct::{expect, roundtrip_json};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Person {
+///     name: String,
+///     id: u32,
+/// }
+///
+/// let value = Person {
+///     name: String::from("Kim Kitsuragi"),
+///     id: 57,
+/// };
+///
+/// expect!(value).to(roundtrip_json());
+/// ```
+///
+/// [`Debug`]: std::fmt::Debug
+/// [`eq_debug_diff`]: crate::eq_debug_diff
+pub fn roundtrip_json<'a, Actual>() -> Matcher<'a, Actual, Actual>
+where
+    Actual: fmt::Debug + PartialEq + Serialize + DeserializeOwned + 'a,
+{
+    Matcher::new(
+        RoundtripJsonMatcher::new(),
+        DiffFormat::<DebugDiff<Actual>, DebugDiff<Actual>>::new(DiffStyle::provided()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::{deserialize_from_json, roundtrip_json, serialize_to_json};
+    use crate::{equal, expect};
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Person {
+        name: String,
+        id: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Lossy {
+        name: String,
+        #[serde(skip)]
+        id: u32,
+    }
+
+    fn person() -> Person {
+        Person {
+            name: String::from("Kim Kitsuragi"),
+            id: 57,
+        }
+    }
+
+    fn lossy() -> Lossy {
+        Lossy {
+            name: String::from("Kim Kitsuragi"),
+            id: 57,
+        }
+    }
+
+    fn json() -> &'static str {
+        r#"{ "id": 57, "name": "Kim Kitsuragi" }"#
+    }
+
+    #[test]
+    fn succeeds_when_serializes_to_json() {
+        expect!(person()).to(serialize_to_json(json()));
+    }
+
+    #[test]
+    fn succeeds_when_not_serializes_to_json() {
+        expect!(person()).to_not(serialize_to_json(r#"{ "id": 41 }"#));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_serializes_to_json() {
+        expect!(person()).to_not(serialize_to_json(json()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_not_serializes_to_json() {
+        expect!(person()).to(serialize_to_json(r#"{ "id": 41 }"#));
+    }
+
+    #[test]
+    fn succeeds_when_deserializes_from_json() {
+        expect!(json())
+            .to(deserialize_from_json::<Person>())
+            .to(equal(person()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_deserializes_from_invalid_json() {
+        expect!(r#"{ "id": 57 }"#).to(deserialize_from_json::<Person>());
+    }
+
+    #[test]
+    fn succeeds_when_roundtrips_json() {
+        expect!(person()).to(roundtrip_json());
+    }
+
+    #[test]
+    fn succeeds_when_not_roundtrips_json() {
+        expect!(lossy()).to_not(roundtrip_json());
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_roundtrips_json() {
+        expect!(person()).to_not(roundtrip_json());
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_not_roundtrips_json() {
+        expect!(lossy()).to(roundtrip_json());
+    }
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::fmt;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::strings::{ContainSubstrMatcher, HavePrefixMatcher, HaveSuffixMatcher};
 
 use super::MismatchFormat;
@@ -21,7 +21,10 @@ where
 {
     Matcher::new(
         ContainSubstrMatcher::new(substr),
-        MismatchFormat::new("to contain the substring", "to not contain the substring"),
+        MismatchFormat::new(
+            Message::id("contain-substr.pos"),
+            Message::id("contain-substr.neg"),
+        ),
     )
 }
 
@@ -40,7 +43,10 @@ where
 {
     Matcher::new(
         HavePrefixMatcher::new(prefix),
-        MismatchFormat::new("to have the prefix", "to not have the prefix"),
+        MismatchFormat::new(
+            Message::id("have-prefix.pos"),
+            Message::id("have-prefix.neg"),
+        ),
     )
 }
 
@@ -59,7 +65,10 @@ where
 {
     Matcher::new(
         HaveSuffixMatcher::new(suffix),
-        MismatchFormat::new("to have the suffix", "to not have the suffix"),
+        MismatchFormat::new(
+            Message::id("have-suffix.pos"),
+            Message::id("have-suffix.neg"),
+        ),
     )
 }
 
@@ -1,6 +1,6 @@
 use std::time::{Duration, SystemTime};
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::time::ApproxEqTimeMatcher;
 
 use super::MismatchFormat;
@@ -27,7 +27,7 @@ pub fn approx_eq_time<'a>(
 ) -> Matcher<'a, SystemTime, SystemTime> {
     Matcher::new(
         ApproxEqTimeMatcher::new(expected, threshold),
-        MismatchFormat::new("to approximately equal", "to not approximately equal"),
+        MismatchFormat::new(Message::id("approx-eq.pos"), Message::id("approx-eq.neg")),
     )
 }
 
@@ -0,0 +1,83 @@
+#![cfg(feature = "toml")]
+
+use std::borrow::Cow;
+use std::fmt;
+
+use toml::Value as TomlValue;
+
+use crate::core::Matcher;
+use crate::matchers::strings::TomlMatcher;
+
+use super::diff::{DiffFormat, DiffStyle};
+
+/// Succeeds when the actual string and the expected string are equivalent TOML.
+///
+/// This allows you to compare TOML documents, ignoring formatting, comments, the order of keys in
+/// tables, and whether tables are written inline. The order of elements in arrays is still
+/// significant, though.
+///
+/// If the TOML is not equivalent, this shows a structural diff of the two values, listing each
+/// value that differs along with its path, like `$.user.roles[2]`.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{expect, match_toml};
+///
+/// let expected = r#"
+/// [package]
+/// name = "xpct"
+/// edition = "2021"
+/// "#;
+///
+/// expect!(r#"package = { edition = "2021", name = "xpct" }"#).to(match_toml(expected));
+/// ```
+pub fn match_toml<'a, Actual>(toml: impl Into<Cow<'a, str>>) -> Matcher<'a, Actual, Actual>
+where
+    Actual: fmt::Debug + AsRef<str> + 'a,
+{
+    Matcher::new(
+        TomlMatcher::new(toml),
+        DiffFormat::<TomlValue, TomlValue>::new(DiffStyle::provided()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::match_toml;
+    use crate::expect;
+
+    fn expected() -> &'static str {
+        r#"
+            key1 = "value1"
+            key2 = "value2"
+        "#
+    }
+
+    fn actual() -> &'static str {
+        r#"key2 = "value2"
+key1 = "value1""#
+    }
+
+    #[test]
+    fn succeeds_when_matches_toml() {
+        expect!(actual()).to(match_toml(expected()));
+    }
+
+    #[test]
+    fn succeeds_when_not_matches_toml() {
+        expect!(actual()).to_not(match_toml(r#"key = "different toml""#));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_matches_toml() {
+        expect!(actual()).to_not(match_toml(expected()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_not_matches_toml() {
+        expect!(actual()).to(match_toml(r#"key = "different toml""#));
+    }
+}
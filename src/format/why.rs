@@ -45,7 +45,7 @@ impl<'a> Format for WhyFormat<'a> {
 
     fn fmt(&self, f: &mut Formatter, value: Self::Value) -> crate::Result<()> {
         f.set_style(style::info());
-        f.write_str(style::WHY_SYMBOL);
+        f.write_str(style::why_symbol());
         f.write_str(" ");
 
         match &self.reason {
@@ -83,7 +83,7 @@ impl<'a> Format for WhyFormat<'a> {
 pub fn why<'a, In, PosOut, NegOut>(
     matcher: Matcher<'a, In, PosOut, NegOut>,
     reason: impl Into<Cow<'a, str>>,
-) -> Matcher<In, PosOut, NegOut>
+) -> Matcher<'a, In, PosOut, NegOut>
 where
     In: 'a,
     PosOut: 'a,
@@ -118,7 +118,7 @@ where
 pub fn why_lazy<'a, In, PosOut, NegOut>(
     matcher: Matcher<'a, In, PosOut, NegOut>,
     reason: impl Fn() -> Cow<'a, str> + 'a,
-) -> Matcher<In, PosOut, NegOut>
+) -> Matcher<'a, In, PosOut, NegOut>
 where
     In: 'a,
     PosOut: 'a,
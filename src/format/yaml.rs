@@ -0,0 +1,80 @@
+#![cfg(feature = "yaml")]
+
+use std::borrow::Cow;
+use std::fmt;
+
+use serde_yaml::Value as YamlValue;
+
+use crate::core::Matcher;
+use crate::matchers::strings::YamlMatcher;
+
+use super::diff::{DiffFormat, DiffStyle};
+
+/// Succeeds when the actual string and the expected string are equivalent YAML.
+///
+/// This allows you to compare YAML strings, ignoring formatting, comments, and the order of keys in
+/// mappings. The order of elements in sequences is still significant, though.
+///
+/// If the YAML is not equivalent, this shows a structural diff of the two values, listing each
+/// value that differs along with its path, like `$.user.roles[2]`.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::{expect, match_yaml};
+///
+/// let expected = "
+/// name: Reál
+/// code: IIR
+/// ";
+///
+/// expect!("{code: IIR, name: Reál}").to(match_yaml(expected));
+/// ```
+pub fn match_yaml<'a, Actual>(yaml: impl Into<Cow<'a, str>>) -> Matcher<'a, Actual, Actual>
+where
+    Actual: fmt::Debug + AsRef<str> + 'a,
+{
+    Matcher::new(
+        YamlMatcher::new(yaml),
+        DiffFormat::<YamlValue, YamlValue>::new(DiffStyle::provided()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::match_yaml;
+    use crate::expect;
+
+    fn expected() -> &'static str {
+        "
+            key1: value1
+            key2: value2
+        "
+    }
+
+    fn actual() -> &'static str {
+        "{key2: value2, key1: value1}"
+    }
+
+    #[test]
+    fn succeeds_when_matches_yaml() {
+        expect!(actual()).to(match_yaml(expected()));
+    }
+
+    #[test]
+    fn succeeds_when_not_matches_yaml() {
+        expect!(actual()).to_not(match_yaml("different yaml"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_matches_yaml() {
+        expect!(actual()).to_not(match_yaml(expected()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fails_when_not_matches_yaml() {
+        expect!(actual()).to(match_yaml("different yaml"));
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::core::Matcher;
+use crate::core::{Matcher, Message};
 use crate::matchers::numbers::{BeZeroMatcher, NonZeroInt};
 
 use super::ExpectationFormat;
@@ -29,7 +29,7 @@ where
 {
     Matcher::transform(
         BeZeroMatcher::new(),
-        ExpectationFormat::new("to be 0", "to not be 0"),
+        ExpectationFormat::new(Message::id("be-zero.pos"), Message::id("be-zero.neg")),
     )
 }
 
@@ -27,6 +27,9 @@ use doc_comment::doctest;
 #[cfg(all(doctest, feature = "regex"))]
 doctest!("../README.md");
 
+// This allows the code generated by the derive macros to refer to `::xpct` in this crate's tests.
+extern crate self as xpct;
+
 pub mod core;
 pub mod docs;
 mod error;
@@ -41,4 +44,5 @@ pub mod format;
 #[cfg(feature = "fmt")]
 pub use format::matchers::*;
 
-pub use error::{Error, Result};
+pub use core::{config, context, expect, set_config, update_config, Config};
+pub use error::{Error, ErrorKind, Result};
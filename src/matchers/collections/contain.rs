@@ -96,19 +96,19 @@ where
     }
 }
 
-impl<'a> Contains<str> for &'a str {
+impl Contains<str> for &str {
     fn contains(&self, element: &str) -> bool {
         <str>::contains(self, element)
     }
 }
 
-impl<'a> Contains<char> for &'a str {
+impl Contains<char> for &str {
     fn contains(&self, element: &char) -> bool {
         <str>::contains(self, *element)
     }
 }
 
-impl<'a> Contains<[char]> for &'a str {
+impl Contains<[char]> for &str {
     fn contains(&self, element: &[char]) -> bool {
         <str>::contains(self, element)
     }
@@ -227,7 +227,7 @@ impl Len for &String {
     }
 }
 
-impl<'a> Len for &'a str {
+impl Len for &str {
     fn len(&self) -> usize {
         <str>::len(self)
     }
@@ -277,7 +277,7 @@ impl Len for &OsString {
     }
 }
 
-impl<'a> Len for &'a OsStr {
+impl Len for &OsStr {
     fn len(&self) -> usize {
         <OsStr>::len(self)
     }
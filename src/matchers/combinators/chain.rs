@@ -1,7 +1,10 @@
-use crate::core::{DynTransformMatch, FormattedFailure, MatchError, MatchOutcome, TransformMatch};
+use crate::core::{DynTransformMatch, MatchError, MatchOutcome, TransformMatch};
+use std::cell::Cell;
 use std::fmt;
+use std::rc::Rc;
 
 use crate::matchers::map::IterMap;
+use crate::matchers::SomeOutcomes;
 
 /// A type used with [`ChainMatcher`] to compose assertions.
 ///
@@ -11,12 +14,13 @@ use crate::matchers::map::IterMap;
 #[derive(Debug)]
 pub struct ChainAssertion<In> {
     value: In,
+    passed: Rc<Cell<usize>>,
 }
 
 impl<In> ChainAssertion<In> {
     /// Create a new [`ChainAssertion`].
-    fn new(value: In) -> Self {
-        Self { value }
+    fn new(value: In, passed: Rc<Cell<usize>>) -> Self {
+        Self { value, passed }
     }
 }
 
@@ -31,7 +35,10 @@ impl<In> ChainAssertion<In> {
         matcher: impl DynTransformMatch<In = In, PosOut = Out>,
     ) -> Result<ChainAssertion<Out>, MatchError> {
         match Box::new(matcher).match_pos(self.value) {
-            Ok(MatchOutcome::Success(out)) => Ok(ChainAssertion::new(out)),
+            Ok(MatchOutcome::Success(out)) => {
+                self.passed.set(self.passed.get() + 1);
+                Ok(ChainAssertion::new(out, self.passed))
+            }
             Ok(MatchOutcome::Fail(fail)) => Err(MatchError::Fail(fail)),
             Err(error) => Err(MatchError::Err(error)),
         }
@@ -50,7 +57,10 @@ impl<In> ChainAssertion<In> {
         matcher: impl DynTransformMatch<In = In, NegOut = Out>,
     ) -> Result<ChainAssertion<Out>, MatchError> {
         match Box::new(matcher).match_neg(self.value) {
-            Ok(MatchOutcome::Success(out)) => Ok(ChainAssertion::new(out)),
+            Ok(MatchOutcome::Success(out)) => {
+                self.passed.set(self.passed.get() + 1);
+                Ok(ChainAssertion::new(out, self.passed))
+            }
             Ok(MatchOutcome::Fail(fail)) => Err(MatchError::Fail(fail)),
             Err(error) => Err(MatchError::Err(error)),
         }
@@ -62,7 +72,7 @@ impl<In> ChainAssertion<In> {
     ///
     /// [`Assertion::map`]: crate::core::Assertion::map
     pub fn map<Out>(self, func: impl FnOnce(In) -> Out) -> ChainAssertion<Out> {
-        ChainAssertion::new(func(self.value))
+        ChainAssertion::new(func(self.value), self.passed)
     }
 
     /// Fallibly map the input value by applying a function to it.
@@ -74,7 +84,7 @@ impl<In> ChainAssertion<In> {
         self,
         func: impl FnOnce(In) -> crate::Result<Out>,
     ) -> crate::Result<ChainAssertion<Out>> {
-        Ok(ChainAssertion::new(func(self.value)?))
+        Ok(ChainAssertion::new(func(self.value)?, self.passed))
     }
 
     /// Infallibly convert the input value via [`From`]/[`Into`].
@@ -86,7 +96,7 @@ impl<In> ChainAssertion<In> {
     where
         Out: From<In>,
     {
-        ChainAssertion::new(self.value.into())
+        ChainAssertion::new(self.value.into(), self.passed)
     }
 
     /// Fallibly convert the input value via [`TryFrom`]/[`TryInto`].
@@ -99,7 +109,7 @@ impl<In> ChainAssertion<In> {
         Out: TryFrom<In>,
         <Out as TryFrom<In>>::Error: std::error::Error + Send + Sync + 'static,
     {
-        Ok(ChainAssertion::new(self.value.try_into()?))
+        Ok(ChainAssertion::new(self.value.try_into()?, self.passed))
     }
 }
 
@@ -116,7 +126,10 @@ where
         self,
         func: impl Fn(In::Item) -> Out + 'a,
     ) -> ChainAssertion<IterMap<'a, In::Item, Out, In::IntoIter>> {
-        ChainAssertion::new(IterMap::new(self.value.into_iter(), Box::new(func)))
+        ChainAssertion::new(
+            IterMap::new(self.value.into_iter(), Box::new(func)),
+            self.passed,
+        )
     }
 
     /// Fallibly map each value of an iterator by applying a function to it.
@@ -134,7 +147,7 @@ where
             .map(func)
             .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(ChainAssertion::new(mapped_values))
+        Ok(ChainAssertion::new(mapped_values, self.passed))
     }
 }
 
@@ -171,16 +184,27 @@ impl<'a, In, Out> TransformMatch for ChainMatcher<'a, In, Out> {
     type PosOut = Out;
     type NegOut = ();
 
-    type PosFail = FormattedFailure;
+    type PosFail = SomeOutcomes;
     type NegFail = ();
 
     fn match_pos(
         self,
         actual: Self::In,
     ) -> crate::Result<MatchOutcome<Self::PosOut, Self::PosFail>> {
-        match (self.func)(ChainAssertion::new(actual)) {
+        let passed = Rc::new(Cell::new(0));
+
+        match (self.func)(ChainAssertion::new(actual, Rc::clone(&passed))) {
             Ok(assertion) => Ok(MatchOutcome::Success(assertion.value)),
-            Err(MatchError::Fail(fail)) => Ok(MatchOutcome::Fail(fail)),
+            Err(MatchError::Fail(fail)) => {
+                // The matchers before the one which failed can't describe themselves, because
+                // their output was passed to the next matcher.
+                let mut outcomes = (0..passed.get())
+                    .map(|_| MatchOutcome::Success(None))
+                    .collect::<SomeOutcomes>();
+                outcomes.push(MatchOutcome::Fail(fail));
+
+                Ok(MatchOutcome::Fail(outcomes))
+            }
             Err(MatchError::Err(error)) => Err(error),
         }
     }
@@ -189,7 +213,7 @@ impl<'a, In, Out> TransformMatch for ChainMatcher<'a, In, Out> {
         self,
         actual: Self::In,
     ) -> crate::Result<MatchOutcome<Self::NegOut, Self::NegFail>> {
-        match (self.func)(ChainAssertion::new(actual)) {
+        match (self.func)(ChainAssertion::new(actual, Rc::new(Cell::new(0)))) {
             Ok(_) => Ok(MatchOutcome::Fail(())),
             Err(MatchError::Fail(_)) => Ok(MatchOutcome::Success(())),
             Err(MatchError::Err(error)) => Err(error),
@@ -1,8 +1,8 @@
 use std::borrow::Borrow;
 use std::fmt;
 
-use crate::core::{DynTransformMatch, MatchOutcome, TransformMatch};
-use crate::matchers::SomeFailures;
+use crate::core::{style, DynTransformMatch, FormattedFailure, MatchOutcome, TransformMatch};
+use crate::matchers::SomeOutcomes;
 
 /// How a combinator matcher should match.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -14,7 +14,7 @@ pub enum CombinatorMode {
     All,
 }
 
-type CombinatorState = crate::Result<SomeFailures>;
+type CombinatorState = crate::Result<SomeOutcomes>;
 
 /// A type used with [`CombinatorMatcher`] to compose assertions.
 pub struct CombinatorAssertion<'a, 'b, T, In> {
@@ -22,6 +22,7 @@ pub struct CombinatorAssertion<'a, 'b, T, In> {
     state: &'b mut CombinatorState,
     transform: Box<dyn Fn(&'a T) -> In + 'b>,
     negated: bool,
+    verbose: bool,
 }
 
 impl<'a, 'b, T, In> fmt::Debug for CombinatorAssertion<'a, 'b, T, In>
@@ -33,45 +34,63 @@ where
             .field("value", &self.value)
             .field("state", &self.state)
             .field("negated", &self.negated)
+            .field("verbose", &self.verbose)
             .finish_non_exhaustive()
     }
 }
 
 impl<'a, 'b: 'a, T, In> CombinatorAssertion<'a, 'b, T, In> {
+    fn push(
+        self,
+        outcome: crate::Result<MatchOutcome<Option<FormattedFailure>, FormattedFailure>>,
+    ) -> Self {
+        match (outcome, &mut *self.state) {
+            (Ok(outcome), Ok(outcomes)) => outcomes.push(outcome),
+            (Err(error), state) => *state = Err(error),
+            (Ok(_), Err(_)) => {}
+        }
+
+        self
+    }
+
     fn match_pos(self, matcher: impl DynTransformMatch<In = In>) -> Self {
-        if let Ok(failures) = self.state {
-            match Box::new(matcher).match_pos((self.transform)(self.value)) {
-                Ok(MatchOutcome::Success(_)) => {
-                    failures.push(None);
-                }
-                Ok(MatchOutcome::Fail(result)) => {
-                    failures.push(Some(result));
-                }
-                Err(error) => {
-                    *self.state = Err(error);
-                }
-            }
+        if self.state.is_err() {
+            return self;
+        }
+
+        let matcher = Box::new(matcher);
+        let actual = (self.transform)(self.value);
+
+        let outcome = if self.verbose {
+            matcher.describe_pos(actual)
+        } else {
+            matcher.match_pos(actual).map(|outcome| match outcome {
+                MatchOutcome::Success(_) => MatchOutcome::Success(None),
+                MatchOutcome::Fail(fail) => MatchOutcome::Fail(fail),
+            })
         };
 
-        self
+        self.push(outcome)
     }
 
     fn match_neg(self, matcher: impl DynTransformMatch<In = In>) -> Self {
-        if let Ok(failures) = self.state {
-            match Box::new(matcher).match_neg((self.transform)(self.value)) {
-                Ok(MatchOutcome::Success(_)) => {
-                    failures.push(None);
-                }
-                Ok(MatchOutcome::Fail(result)) => {
-                    failures.push(Some(result));
-                }
-                Err(error) => {
-                    *self.state = Err(error);
-                }
-            }
+        if self.state.is_err() {
+            return self;
+        }
+
+        let matcher = Box::new(matcher);
+        let actual = (self.transform)(self.value);
+
+        let outcome = if self.verbose {
+            matcher.describe_neg(actual)
+        } else {
+            matcher.match_neg(actual).map(|outcome| match outcome {
+                MatchOutcome::Success(_) => MatchOutcome::Success(None),
+                MatchOutcome::Fail(fail) => MatchOutcome::Fail(fail),
+            })
         };
 
-        self
+        self.push(outcome)
     }
 
     /// Make an assertion with the given `matcher`.
@@ -132,19 +151,21 @@ pub struct CombinatorContext<T> {
     value: T,
     state: CombinatorState,
     negated: bool,
+    verbose: bool,
 }
 
 impl<T> CombinatorContext<T> {
-    fn new(value: T, negated: bool) -> Self {
+    fn new(value: T, negated: bool, verbose: bool) -> Self {
         CombinatorContext {
             value,
             state: Ok(Vec::new()),
             negated,
+            verbose,
         }
     }
 
     /// Borrow the owned value before making assertions on it.
-    pub fn borrow<Borrowed: ?Sized>(&mut self) -> CombinatorAssertion<T, &Borrowed>
+    pub fn borrow<Borrowed: ?Sized>(&mut self) -> CombinatorAssertion<'_, '_, T, &Borrowed>
     where
         T: Borrow<Borrowed>,
     {
@@ -164,6 +185,7 @@ impl<T> CombinatorContext<T> {
             state: &mut self.state,
             transform: Box::new(func),
             negated: self.negated,
+            verbose: self.verbose,
         }
     }
 }
@@ -173,12 +195,13 @@ where
     T: Copy,
 {
     /// Copy the owned value before making assertions on it.
-    pub fn copied(&mut self) -> CombinatorAssertion<T, T> {
+    pub fn copied(&mut self) -> CombinatorAssertion<'_, '_, T, T> {
         CombinatorAssertion {
             value: &self.value,
             state: &mut self.state,
             transform: Box::new(|value| *value),
             negated: self.negated,
+            verbose: self.verbose,
         }
     }
 }
@@ -188,12 +211,13 @@ where
     T: Clone,
 {
     /// Clone the owned value before making assertions on it.
-    pub fn cloned(&mut self) -> CombinatorAssertion<T, T> {
+    pub fn cloned(&mut self) -> CombinatorAssertion<'_, '_, T, T> {
         CombinatorAssertion {
             value: &self.value,
             state: &mut self.state,
             transform: Box::new(|value| value.clone()),
             negated: self.negated,
+            verbose: self.verbose,
         }
     }
 }
@@ -207,12 +231,14 @@ type BoxCombinatorFunc<'a, T> = Box<dyn FnOnce(&mut CombinatorContext<T>) + 'a>;
 pub struct CombinatorMatcher<'a, T> {
     mode: CombinatorMode,
     func: BoxCombinatorFunc<'a, T>,
+    verbose: Option<bool>,
 }
 
 impl<'a, T> fmt::Debug for CombinatorMatcher<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("CombinatorMatcher")
             .field("mode", &self.mode)
+            .field("verbose", &self.verbose)
             .finish_non_exhaustive()
     }
 }
@@ -228,8 +254,24 @@ impl<'a, T> CombinatorMatcher<'a, T> {
         Self {
             mode,
             func: Box::new(block),
+            verbose: None,
         }
     }
+
+    /// Whether to record a description of each matcher which succeeded.
+    ///
+    /// This overrides [`Config::verbose`] for this matcher. Recording descriptions means formatting
+    /// the output of matchers even when they succeed, so this is disabled by default.
+    ///
+    /// [`Config::verbose`]: crate::Config::verbose
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
+    }
+
+    fn is_verbose(&self) -> bool {
+        self.verbose.unwrap_or_else(style::verbose)
+    }
 }
 
 impl<'a, T> TransformMatch for CombinatorMatcher<'a, T> {
@@ -238,28 +280,28 @@ impl<'a, T> TransformMatch for CombinatorMatcher<'a, T> {
     type PosOut = T;
     type NegOut = T;
 
-    type PosFail = SomeFailures;
-    type NegFail = SomeFailures;
+    type PosFail = SomeOutcomes;
+    type NegFail = SomeOutcomes;
 
     fn match_pos(
         self,
         actual: Self::In,
     ) -> crate::Result<MatchOutcome<Self::PosOut, Self::PosFail>> {
-        let mut ctx = CombinatorContext::new(actual, false);
+        let mut ctx = CombinatorContext::new(actual, false, self.is_verbose());
 
         (self.func)(&mut ctx);
 
         match (ctx.state, self.mode) {
-            (Ok(failures), CombinatorMode::Any) => {
-                if failures.iter().any(Option::is_none) {
+            (Ok(outcomes), CombinatorMode::Any) => {
+                if outcomes.iter().any(MatchOutcome::is_success) {
                     Ok(MatchOutcome::Success(ctx.value))
                 } else {
-                    Ok(MatchOutcome::Fail(failures))
+                    Ok(MatchOutcome::Fail(outcomes))
                 }
             }
-            (Ok(failures), CombinatorMode::All) => {
-                if failures.iter().any(Option::is_some) {
-                    Ok(MatchOutcome::Fail(failures))
+            (Ok(outcomes), CombinatorMode::All) => {
+                if outcomes.iter().any(MatchOutcome::is_fail) {
+                    Ok(MatchOutcome::Fail(outcomes))
                 } else {
                     Ok(MatchOutcome::Success(ctx.value))
                 }
@@ -272,23 +314,23 @@ impl<'a, T> TransformMatch for CombinatorMatcher<'a, T> {
         self,
         actual: Self::In,
     ) -> crate::Result<MatchOutcome<Self::NegOut, Self::NegFail>> {
-        let mut ctx = CombinatorContext::new(actual, true);
+        let mut ctx = CombinatorContext::new(actual, true, self.is_verbose());
 
         (self.func)(&mut ctx);
 
         match (ctx.state, self.mode) {
-            (Ok(failures), CombinatorMode::Any) => {
-                if failures.iter().any(Option::is_some) {
-                    Ok(MatchOutcome::Fail(failures))
+            (Ok(outcomes), CombinatorMode::Any) => {
+                if outcomes.iter().any(MatchOutcome::is_fail) {
+                    Ok(MatchOutcome::Fail(outcomes))
                 } else {
                     Ok(MatchOutcome::Success(ctx.value))
                 }
             }
-            (Ok(failures), CombinatorMode::All) => {
-                if failures.iter().any(Option::is_none) {
+            (Ok(outcomes), CombinatorMode::All) => {
+                if outcomes.iter().any(MatchOutcome::is_success) {
                     Ok(MatchOutcome::Success(ctx.value))
                 } else {
-                    Ok(MatchOutcome::Fail(failures))
+                    Ok(MatchOutcome::Fail(outcomes))
                 }
             }
             (Err(error), _) => Err(error),
@@ -5,6 +5,9 @@ use similar::ChangeTag;
 
 use crate::core::Match;
 
+#[cfg(feature = "derive")]
+pub use xpct_derive::Diffable;
+
 /// A discriminant that represents how a diff should be formatted.
 ///
 /// You would format a diff of two strings differently from a diff of two slices. Diff "kinds" exist
@@ -31,6 +34,31 @@ pub enum DiffKind {
     /// Diffing maps.
     Map,
 
+    /// Diffing structured data, like JSON, by the path of each value from the root.
+    ///
+    /// Each [`DiffSegment`] in a diff of this kind is a single value prefixed with its path, like
+    /// `$.user.roles[2]: "admin"`.
+    KeyPath,
+
+    /// Diffing text line by line, like the pretty-printed [`Debug`] output of a value.
+    ///
+    /// Each [`DiffSegment`] in a diff of this kind is a single line without its trailing newline.
+    /// The provided formatter highlights the changes within each line that was replaced.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    Line,
+
+    /// Diffing byte buffers, shown as a hex dump.
+    ///
+    /// Each [`DiffSegment`] in a diff of this kind is a row of a hex dump with up to 16 columns,
+    /// in the format of `hexdump -C`: the offset of the row, the bytes as lowercase hex digits,
+    /// and the bytes as ASCII characters. The bytes of the expected and actual values are
+    /// aligned, so a column is blank in one value where a byte was inserted in or deleted from
+    /// the other. A row which changed is a deletion of the row in the expected value followed by
+    /// an insertion of the row in the actual value. The provided formatter highlights the
+    /// columns that differ.
+    Bytes,
+
     /// Provide your own custom diff kind.
     Custom(&'static str),
 }
@@ -111,6 +139,54 @@ pub type Diff = Vec<DiffSegment>;
 /// A value which can be diffed against another value.
 ///
 /// Diffing two values produces a [`Diff`], which consists of a list of [`DiffSegment`]s.
+///
+/// # Deriving
+///
+/// With the `derive` Cargo feature enabled, you can derive this trait for your own structs and
+/// enums. The derived implementation diffs each field by its [`Debug`] representation, and the
+/// diff shows which fields changed by name. Each field must implement [`PartialEq`] and [`Debug`],
+/// and enums must also implement [`Debug`].
+///
+/// If a field implements [`Diffable`] itself, like another struct that derives it, you can mark it
+/// with `#[diffable(nested)]` to show which of its fields changed too, like `address.city`. This
+/// only nests fields whose diff has [`DiffKind::Map`], including an [`Option`] of one; other nested
+/// fields, like a [`Vec`], are shown whole. See [`nest_diff`].
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use xpct::{expect, eq_diff};
+/// use xpct::matchers::diff::Diffable;
+///
+/// #[derive(Debug, PartialEq, Eq, Diffable)]
+/// struct Address {
+///     city: String,
+/// }
+///
+/// #[derive(Debug, PartialEq, Eq, Diffable)]
+/// struct Person {
+///     name: String,
+///     #[diffable(nested)]
+///     address: Address,
+/// }
+///
+/// let person = Person {
+///     name: String::from("Kim Kitsuragi"),
+///     address: Address {
+///         city: String::from("Revachol"),
+///     },
+/// };
+///
+/// expect!(person).to(eq_diff(Person {
+///     name: String::from("Kim Kitsuragi"),
+///     address: Address {
+///         city: String::from("Revachol"),
+///     },
+/// }));
+/// # }
+/// ```
+///
+/// [`Debug`]: std::fmt::Debug
 pub trait Diffable<Other> {
     /// A discriminant that represents how the diff should be formatted.
     ///
@@ -119,20 +195,246 @@ pub trait Diffable<Other> {
 
     /// Generate a diff of this value and `other`.
     fn diff(&self, other: Other) -> Diff;
+
+    /// Generate a diff of this value and `other` using the given options.
+    ///
+    /// The default implementation ignores `options` and calls [`diff`]. The provided
+    /// implementations for strings, sequences, and [`DebugDiff`] respect them.
+    ///
+    /// [`diff`]: crate::matchers::diff::Diffable::diff
+    fn diff_with(&self, other: Other, options: &DiffOptions) -> Diff {
+        let _ = options;
+        self.diff(other)
+    }
 }
 
+/// How finely to split strings when diffing them.
+///
+/// See [`DiffOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiffGranularity {
+    /// Diff strings by character.
+    Char,
+
+    /// Diff strings by word, treating runs of whitespace as their own words.
+    Word,
+
+    /// Diff strings by line.
+    ///
+    /// Each [`DiffSegment`] in a diff with this granularity is a single line without its trailing
+    /// newline, like with [`DiffKind::Line`]. The provided formatter highlights the changes within
+    /// each line that was replaced.
+    ///
+    /// If only one of the strings ends with a newline, the last line of the other is followed by a
+    /// `\ No newline at end of file` segment, like in `git diff`.
+    Line,
+
+    /// Diff strings by grapheme cluster.
+    ///
+    /// This requires the `unicode-diff` Cargo feature. Without it, strings are diffed by character
+    /// instead.
+    Grapheme,
+}
+
+impl Default for DiffGranularity {
+    fn default() -> Self {
+        if cfg!(feature = "unicode-diff") {
+            Self::Grapheme
+        } else {
+            Self::Char
+        }
+    }
+}
+
+/// The algorithm to use when diffing values.
+///
+/// See [`DiffOptions`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiffAlgorithm {
+    /// The Myers diff algorithm.
+    Myers,
+
+    /// The patience diff algorithm.
+    #[default]
+    Patience,
+
+    /// A diff algorithm based on the longest common subsequence.
+    Lcs,
+}
+
+impl DiffAlgorithm {
+    pub(super) fn to_similar(self) -> similar::Algorithm {
+        match self {
+            Self::Myers => similar::Algorithm::Myers,
+            Self::Patience => similar::Algorithm::Patience,
+            Self::Lcs => similar::Algorithm::Lcs,
+        }
+    }
+}
+
+/// Options for how to diff two values, used by [`Diffable::diff_with`].
+///
+/// The [`Default`] options are what [`eq_diff`] uses.
+///
+/// # Examples
+///
+/// ```
+/// use xpct::matchers::diff::{DiffAlgorithm, DiffGranularity, DiffOptions};
+///
+/// let options = DiffOptions {
+///     granularity: DiffGranularity::Word,
+///     algorithm: DiffAlgorithm::Myers,
+/// };
+/// ```
+///
+/// [`eq_diff`]: crate::eq_diff
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiffOptions {
+    /// How finely to split strings when diffing them.
+    ///
+    /// This only affects diffs of strings.
+    pub granularity: DiffGranularity,
+
+    /// The algorithm to use when diffing values.
+    ///
+    /// This affects diffs of strings and of sequences like slices, [`Vec`]s, and [`VecDeque`]s.
+    /// Sets and maps are diffed by which elements or keys they contain, so this doesn't affect
+    /// them.
+    ///
+    /// [`VecDeque`]: std::collections::VecDeque
+    pub algorithm: DiffAlgorithm,
+}
+
+/// Diff a single field of a struct by its [`Debug`] representation.
+///
+/// This is a helper for implementing [`Diffable`] for structs with [`DiffKind::Map`], and it's used
+/// by `#[derive(Diffable)]`. If the field is unchanged, this returns a single segment like `name:
+/// "Kim"`. Otherwise, it returns a deletion of the expected value followed by an insertion of the
+/// actual value.
+///
+/// [`Debug`]: std::fmt::Debug
+pub fn diff_field<Expected, Actual>(name: &str, expected: &Expected, actual: &Actual) -> Diff
+where
+    Expected: PartialEq<Actual> + fmt::Debug,
+    Actual: fmt::Debug,
+{
+    if expected == actual {
+        vec![DiffSegment {
+            value: format!("{}: {:?}", name, expected),
+            tag: DiffTag::Equal,
+        }]
+    } else {
+        vec![
+            DiffSegment {
+                value: format!("{}: {:?}", name, expected),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: format!("{}: {:?}", name, actual),
+                tag: DiffTag::Insert,
+            },
+        ]
+    }
+}
+
+/// Diff a field of a struct which implements [`Diffable`] itself, nesting its diff under the name
+/// of that field.
+///
+/// This is a helper for implementing [`Diffable`] for structs with [`DiffKind::Map`], and it's used
+/// by `#[derive(Diffable)]` for fields marked `#[diffable(nested)]`. If the field's type also has
+/// [`DiffKind::Map`], each segment of its diff is prefixed with the name of the field, like
+/// `address.city: "Revachol"`. Segments which show the whole value of the field, like when an
+/// [`Option`] is `Some` on one side and `None` on the other, are shown like `address: None`
+/// instead.
+///
+/// Other kinds of diffs can't be shown as fields, so for any other [`DiffKind`], this falls back to
+/// [`diff_field`].
+pub fn nest_diff<T>(name: &str, expected: &T, actual: T) -> Diff
+where
+    T: Diffable<T> + PartialEq + fmt::Debug,
+{
+    if T::KIND != DiffKind::Map {
+        return diff_field(name, expected, &actual);
+    }
+
+    let whole_values = [format!("{:?}", expected), format!("{:?}", actual)];
+
+    expected
+        .diff(actual)
+        .into_iter()
+        .map(|segment| {
+            let separator = if whole_values.contains(&segment.value) {
+                ": "
+            } else {
+                "."
+            };
+
+            DiffSegment {
+                value: format!("{}{}{}", name, separator, segment.value),
+                tag: segment.tag,
+            }
+        })
+        .collect()
+}
+
+/// A wrapper which diffs any value by its pretty-printed [`Debug`] representation.
+///
+/// This implements [`Diffable`] for any type that implements [`Debug`], which is useful for types
+/// that don't implement [`Diffable`] themselves, like your own structs. Both values are formatted
+/// with `{:#?}` and then diffed line by line.
+///
+/// This is used to implement [`eq_debug_diff`].
+///
+/// [`Debug`]: std::fmt::Debug
+/// [`eq_debug_diff`]: crate::eq_debug_diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DebugDiff<T>(pub T);
+
+/// The number of bytes in each row of a [`DiffKind::Bytes`] diff.
+pub(crate) const BYTES_PER_ROW: usize = 16;
+
+#[cfg(feature = "fmt")]
+pub(crate) use super::diff_impl::{hex_row_cells, HexCell};
+
+#[cfg(feature = "fmt")]
+pub(crate) use super::diff_impl::is_no_newline_marker;
+
+/// A wrapper which diffs byte buffers as a hex dump.
+///
+/// This implements [`Diffable`] for any type that implements `AsRef<[u8]>`, like `Vec<u8>` or
+/// `&[u8]`, with [`DiffKind::Bytes`]. The values are diffed byte by byte, so inserting or deleting
+/// a byte only changes the row it's in, and the result is shown as a hex dump with 16 bytes per
+/// row.
+///
+/// This is used to implement [`eq_bytes`].
+///
+/// [`eq_bytes`]: crate::eq_bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BytesDiff<T>(pub T);
+
 /// The matcher for [`eq_diff`].
 ///
 /// [`eq_diff`]: crate::eq_diff
 #[derive(Debug)]
 pub struct EqDiffMatcher<Expected> {
     expected: Expected,
+    options: DiffOptions,
 }
 
 impl<Expected> EqDiffMatcher<Expected> {
     /// Create a new [`EqDiffMatcher`] from the expected value.
     pub fn new(expected: Expected) -> Self {
-        Self { expected }
+        Self {
+            expected,
+            options: DiffOptions::default(),
+        }
+    }
+
+    /// Create a new [`EqDiffMatcher`] from the expected value and the options for diffing.
+    pub fn with_options(expected: Expected, options: DiffOptions) -> Self {
+        Self { expected, options }
     }
 }
 
@@ -148,6 +450,68 @@ where
     }
 
     fn fail(self, actual: Actual) -> Self::Fail {
-        self.expected.diff(actual)
+        self.expected.diff_with(actual, &self.options)
+    }
+}
+
+/// The matcher for [`eq_debug_diff`].
+///
+/// [`eq_debug_diff`]: crate::eq_debug_diff
+#[derive(Debug)]
+pub struct EqDebugDiffMatcher<Expected> {
+    expected: Expected,
+}
+
+impl<Expected> EqDebugDiffMatcher<Expected> {
+    /// Create a new [`EqDebugDiffMatcher`] from the expected value.
+    pub fn new(expected: Expected) -> Self {
+        Self { expected }
+    }
+}
+
+impl<Expected, Actual> Match<Actual> for EqDebugDiffMatcher<Expected>
+where
+    Actual: PartialEq<Expected> + fmt::Debug,
+    Expected: fmt::Debug,
+{
+    type Fail = Diff;
+
+    fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
+        Ok(actual == &self.expected)
+    }
+
+    fn fail(self, actual: Actual) -> Self::Fail {
+        DebugDiff(self.expected).diff(DebugDiff(actual))
+    }
+}
+
+/// The matcher for [`eq_bytes`].
+///
+/// [`eq_bytes`]: crate::eq_bytes
+#[derive(Debug)]
+pub struct EqBytesMatcher<Expected> {
+    expected: Expected,
+}
+
+impl<Expected> EqBytesMatcher<Expected> {
+    /// Create a new [`EqBytesMatcher`] from the expected value.
+    pub fn new(expected: Expected) -> Self {
+        Self { expected }
+    }
+}
+
+impl<Expected, Actual> Match<Actual> for EqBytesMatcher<Expected>
+where
+    Actual: AsRef<[u8]>,
+    Expected: AsRef<[u8]>,
+{
+    type Fail = Diff;
+
+    fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
+        Ok(actual.as_ref() == self.expected.as_ref())
+    }
+
+    fn fail(self, actual: Actual) -> Self::Fail {
+        BytesDiff(self.expected).diff(BytesDiff(actual))
     }
 }
@@ -1,28 +1,85 @@
 use std::borrow::Cow;
 use std::cmp;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
 use std::fmt;
 use std::hash::Hash;
+use std::rc::Rc;
+use std::sync::Arc;
 
 use similar::{capture_diff_slices, utils::TextDiffRemapper, TextDiff};
 
-use super::diff::{Diff, DiffKind, DiffSegment, DiffTag, Diffable};
+use super::diff::{
+    diff_field, BytesDiff, DebugDiff, Diff, DiffGranularity, DiffKind, DiffOptions, DiffSegment,
+    DiffTag, Diffable, BYTES_PER_ROW,
+};
+
+/// The segment which follows the last line of a string without a trailing newline, like in `git
+/// diff`.
+pub(crate) const NO_NEWLINE_MARKER: &str = "\\ No newline at end of file";
+
+/// Returns whether the segment at `index` in a line diff is a [`NO_NEWLINE_MARKER`] rather than a
+/// line.
+///
+/// The marker always follows the line it annotates, with the same tag.
+#[cfg(feature = "fmt")]
+pub(crate) fn is_no_newline_marker(diff: &Diff, index: usize) -> bool {
+    let segment = &diff[index];
+
+    segment.tag != DiffTag::Equal
+        && segment.value == NO_NEWLINE_MARKER
+        && index > 0
+        && diff[index - 1].tag == segment.tag
+}
+
+/// Diff two strings line by line, with one segment per line without its trailing newline.
+///
+/// If only one of the strings ends with a newline, the last line of the other is followed by a
+/// [`NO_NEWLINE_MARKER`] segment, so the difference is visible.
+fn diff_lines(old: &str, new: &str, options: &DiffOptions) -> Diff {
+    let mark_missing_newline = old.ends_with('\n') != new.ends_with('\n');
+    let mut segments = Vec::new();
+
+    for change in TextDiff::configure()
+        .algorithm(options.algorithm.to_similar())
+        .diff_lines(old, new)
+        .iter_all_changes()
+    {
+        let tag = DiffTag::from_tag(change.tag());
+
+        segments.push(DiffSegment {
+            value: change.value().trim_end_matches('\n').to_string(),
+            tag,
+        });
+
+        if mark_missing_newline && change.missing_newline() {
+            segments.push(DiffSegment {
+                value: String::from(NO_NEWLINE_MARKER),
+                tag,
+            });
+        }
+    }
 
-const DIFF_ALGORITHM: similar::Algorithm = similar::Algorithm::Patience;
+    segments
+}
 
 impl<'a> Diffable<&'a str> for &'a str {
     const KIND: DiffKind = DiffKind::String;
 
     fn diff(&self, other: &'a str) -> Diff {
-        #[cfg(feature = "unicode-diff")]
-        let text_diff = TextDiff::configure()
-            .algorithm(DIFF_ALGORITHM)
-            .diff_graphemes(*self, other);
+        self.diff_with(other, &DiffOptions::default())
+    }
 
-        #[cfg(not(feature = "unicode-diff"))]
-        let text_diff = TextDiff::configure()
-            .algorithm(DIFF_ALGORITHM)
-            .diff_chars(*self, other);
+    fn diff_with(&self, other: &'a str, options: &DiffOptions) -> Diff {
+        let mut config = TextDiff::configure();
+        config.algorithm(options.algorithm.to_similar());
+
+        let text_diff = match options.granularity {
+            DiffGranularity::Word => config.diff_words(*self, other),
+            DiffGranularity::Line => return diff_lines(self, other, options),
+            #[cfg(feature = "unicode-diff")]
+            DiffGranularity::Grapheme => config.diff_graphemes(*self, other),
+            _ => config.diff_chars(*self, other),
+        };
 
         let remapper = TextDiffRemapper::from_text_diff(&text_diff, self, other);
 
@@ -35,12 +92,16 @@ impl<'a> Diffable<&'a str> for &'a str {
     }
 }
 
-impl<'a> Diffable<String> for &'a str {
+impl Diffable<String> for &str {
     const KIND: DiffKind = DiffKind::String;
 
     fn diff(&self, other: String) -> Diff {
         self.diff(other.as_str())
     }
+
+    fn diff_with(&self, other: String, options: &DiffOptions) -> Diff {
+        self.diff_with(other.as_str(), options)
+    }
 }
 
 impl<'a> Diffable<Cow<'a, str>> for &'a str {
@@ -49,6 +110,10 @@ impl<'a> Diffable<Cow<'a, str>> for &'a str {
     fn diff(&self, other: Cow<'a, str>) -> Diff {
         self.diff(other.as_ref())
     }
+
+    fn diff_with(&self, other: Cow<'a, str>, options: &DiffOptions) -> Diff {
+        self.diff_with(other.as_ref(), options)
+    }
 }
 
 impl<'a> Diffable<&'a str> for String {
@@ -57,6 +122,10 @@ impl<'a> Diffable<&'a str> for String {
     fn diff(&self, other: &'a str) -> Diff {
         self.as_str().diff(other)
     }
+
+    fn diff_with(&self, other: &'a str, options: &DiffOptions) -> Diff {
+        self.as_str().diff_with(other, options)
+    }
 }
 
 impl Diffable<String> for String {
@@ -65,6 +134,10 @@ impl Diffable<String> for String {
     fn diff(&self, other: String) -> Diff {
         self.as_str().diff(other.as_str())
     }
+
+    fn diff_with(&self, other: String, options: &DiffOptions) -> Diff {
+        self.as_str().diff_with(other.as_str(), options)
+    }
 }
 
 impl<'a> Diffable<Cow<'a, str>> for String {
@@ -73,6 +146,10 @@ impl<'a> Diffable<Cow<'a, str>> for String {
     fn diff(&self, other: Cow<'a, str>) -> Diff {
         self.as_str().diff(other.as_ref())
     }
+
+    fn diff_with(&self, other: Cow<'a, str>, options: &DiffOptions) -> Diff {
+        self.as_str().diff_with(other.as_ref(), options)
+    }
 }
 
 impl<'a> Diffable<&'a str> for Cow<'a, str> {
@@ -81,6 +158,10 @@ impl<'a> Diffable<&'a str> for Cow<'a, str> {
     fn diff(&self, other: &'a str) -> Diff {
         self.as_ref().diff(other)
     }
+
+    fn diff_with(&self, other: &'a str, options: &DiffOptions) -> Diff {
+        self.as_ref().diff_with(other, options)
+    }
 }
 
 impl<'a> Diffable<String> for Cow<'a, str> {
@@ -89,6 +170,10 @@ impl<'a> Diffable<String> for Cow<'a, str> {
     fn diff(&self, other: String) -> Diff {
         self.as_ref().diff(other.as_str())
     }
+
+    fn diff_with(&self, other: String, options: &DiffOptions) -> Diff {
+        self.as_ref().diff_with(other.as_str(), options)
+    }
 }
 
 impl<'a> Diffable<Cow<'a, str>> for Cow<'a, str> {
@@ -97,6 +182,10 @@ impl<'a> Diffable<Cow<'a, str>> for Cow<'a, str> {
     fn diff(&self, other: Cow<'a, str>) -> Diff {
         self.as_ref().diff(other.as_ref())
     }
+
+    fn diff_with(&self, other: Cow<'a, str>, options: &DiffOptions) -> Diff {
+        self.as_ref().diff_with(other.as_ref(), options)
+    }
 }
 
 impl<'a, T> Diffable<&'a [T]> for &'a [T]
@@ -106,7 +195,11 @@ where
     const KIND: DiffKind = DiffKind::Slice;
 
     fn diff(&self, other: &'a [T]) -> Diff {
-        capture_diff_slices(DIFF_ALGORITHM, self, other)
+        self.diff_with(other, &DiffOptions::default())
+    }
+
+    fn diff_with(&self, other: &'a [T], options: &DiffOptions) -> Diff {
+        capture_diff_slices(options.algorithm.to_similar(), self, other)
             .into_iter()
             .flat_map(|op| op.iter_changes(*self, other))
             .map(|change| DiffSegment::from_debug(change.value(), DiffTag::from_tag(change.tag())))
@@ -114,7 +207,7 @@ where
     }
 }
 
-impl<'a, T, const OTHER_LEN: usize> Diffable<[T; OTHER_LEN]> for &'a [T]
+impl<T, const OTHER_LEN: usize> Diffable<[T; OTHER_LEN]> for &[T]
 where
     T: Clone + Hash + Ord + fmt::Debug,
 {
@@ -123,9 +216,13 @@ where
     fn diff(&self, other: [T; OTHER_LEN]) -> Diff {
         self.diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: [T; OTHER_LEN], options: &DiffOptions) -> Diff {
+        self.diff_with(other.as_slice(), options)
+    }
 }
 
-impl<'a, T, const OTHER_LEN: usize> Diffable<&[T; OTHER_LEN]> for &'a [T]
+impl<T, const OTHER_LEN: usize> Diffable<&[T; OTHER_LEN]> for &[T]
 where
     T: Clone + Hash + Ord + fmt::Debug,
 {
@@ -134,9 +231,13 @@ where
     fn diff(&self, other: &[T; OTHER_LEN]) -> Diff {
         self.diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: &[T; OTHER_LEN], options: &DiffOptions) -> Diff {
+        self.diff_with(other.as_slice(), options)
+    }
 }
 
-impl<'a, T> Diffable<Vec<T>> for &'a [T]
+impl<T> Diffable<Vec<T>> for &[T]
 where
     T: Clone + Hash + Ord + fmt::Debug,
 {
@@ -145,9 +246,13 @@ where
     fn diff(&self, other: Vec<T>) -> Diff {
         self.diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: Vec<T>, options: &DiffOptions) -> Diff {
+        self.diff_with(other.as_slice(), options)
+    }
 }
 
-impl<'a, T> Diffable<&Vec<T>> for &'a [T]
+impl<T> Diffable<&Vec<T>> for &[T]
 where
     T: Clone + Hash + Ord + fmt::Debug,
 {
@@ -156,6 +261,10 @@ where
     fn diff(&self, other: &Vec<T>) -> Diff {
         self.diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: &Vec<T>, options: &DiffOptions) -> Diff {
+        self.diff_with(other.as_slice(), options)
+    }
 }
 
 impl<'a, T, const LEN: usize> Diffable<&'a [T]> for [T; LEN]
@@ -167,6 +276,10 @@ where
     fn diff(&self, other: &'a [T]) -> Diff {
         self.as_slice().diff(other)
     }
+
+    fn diff_with(&self, other: &'a [T], options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other, options)
+    }
 }
 
 impl<T, const LEN: usize, const OTHER_LEN: usize> Diffable<[T; OTHER_LEN]> for [T; LEN]
@@ -178,6 +291,10 @@ where
     fn diff(&self, other: [T; OTHER_LEN]) -> Diff {
         self.as_slice().diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: [T; OTHER_LEN], options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other.as_slice(), options)
+    }
 }
 
 impl<T, const LEN: usize> Diffable<Vec<T>> for [T; LEN]
@@ -189,6 +306,10 @@ where
     fn diff(&self, other: Vec<T>) -> Diff {
         self.as_slice().diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: Vec<T>, options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other.as_slice(), options)
+    }
 }
 
 impl<'a, T, const LEN: usize> Diffable<&'a [T]> for &[T; LEN]
@@ -200,6 +321,10 @@ where
     fn diff(&self, other: &'a [T]) -> Diff {
         self.as_slice().diff(other)
     }
+
+    fn diff_with(&self, other: &'a [T], options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other, options)
+    }
 }
 
 impl<T, const LEN: usize, const OTHER_LEN: usize> Diffable<&[T; OTHER_LEN]> for &[T; LEN]
@@ -211,6 +336,10 @@ where
     fn diff(&self, other: &[T; OTHER_LEN]) -> Diff {
         self.as_slice().diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: &[T; OTHER_LEN], options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other.as_slice(), options)
+    }
 }
 
 impl<T, const LEN: usize> Diffable<Vec<T>> for &[T; LEN]
@@ -222,6 +351,10 @@ where
     fn diff(&self, other: Vec<T>) -> Diff {
         self.as_slice().diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: Vec<T>, options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other.as_slice(), options)
+    }
 }
 
 impl<T, const LEN: usize> Diffable<&Vec<T>> for &[T; LEN]
@@ -233,6 +366,10 @@ where
     fn diff(&self, other: &Vec<T>) -> Diff {
         self.as_slice().diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: &Vec<T>, options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other.as_slice(), options)
+    }
 }
 
 impl<'a, T> Diffable<&'a [T]> for Vec<T>
@@ -244,6 +381,10 @@ where
     fn diff(&self, other: &'a [T]) -> Diff {
         self.as_slice().diff(other)
     }
+
+    fn diff_with(&self, other: &'a [T], options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other, options)
+    }
 }
 
 impl<T> Diffable<Vec<T>> for Vec<T>
@@ -255,6 +396,10 @@ where
     fn diff(&self, other: Vec<T>) -> Diff {
         self.as_slice().diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: Vec<T>, options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other.as_slice(), options)
+    }
 }
 
 impl<'a, T> Diffable<&'a [T]> for &Vec<T>
@@ -266,6 +411,10 @@ where
     fn diff(&self, other: &'a [T]) -> Diff {
         self.as_slice().diff(other)
     }
+
+    fn diff_with(&self, other: &'a [T], options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other, options)
+    }
 }
 
 impl<T> Diffable<&Vec<T>> for &Vec<T>
@@ -277,6 +426,10 @@ where
     fn diff(&self, other: &Vec<T>) -> Diff {
         self.as_slice().diff(other.as_slice())
     }
+
+    fn diff_with(&self, other: &Vec<T>, options: &DiffOptions) -> Diff {
+        self.as_slice().diff_with(other.as_slice(), options)
+    }
 }
 
 impl<T> Diffable<&HashSet<T>> for &HashSet<T>
@@ -521,9 +674,422 @@ where
     }
 }
 
+impl<T> Diffable<&VecDeque<T>> for &VecDeque<T>
+where
+    T: Clone + Hash + Ord + fmt::Debug,
+{
+    const KIND: DiffKind = DiffKind::Slice;
+
+    fn diff(&self, other: &VecDeque<T>) -> Diff {
+        self.diff_with(other, &DiffOptions::default())
+    }
+
+    fn diff_with(&self, other: &VecDeque<T>, options: &DiffOptions) -> Diff {
+        let this = self.iter().collect::<Vec<_>>();
+        let other = other.iter().collect::<Vec<_>>();
+
+        this.as_slice().diff_with(other.as_slice(), options)
+    }
+}
+
+impl<T> Diffable<VecDeque<T>> for VecDeque<T>
+where
+    T: Clone + Hash + Ord + fmt::Debug,
+{
+    const KIND: DiffKind = DiffKind::Slice;
+
+    fn diff(&self, other: VecDeque<T>) -> Diff {
+        <&VecDeque<T>>::diff(&self, &other)
+    }
+
+    fn diff_with(&self, other: VecDeque<T>, options: &DiffOptions) -> Diff {
+        <&VecDeque<T>>::diff_with(&self, &other, options)
+    }
+}
+
+impl<T> Diffable<&LinkedList<T>> for &LinkedList<T>
+where
+    T: Clone + Hash + Ord + fmt::Debug,
+{
+    const KIND: DiffKind = DiffKind::Slice;
+
+    fn diff(&self, other: &LinkedList<T>) -> Diff {
+        self.diff_with(other, &DiffOptions::default())
+    }
+
+    fn diff_with(&self, other: &LinkedList<T>, options: &DiffOptions) -> Diff {
+        let this = self.iter().collect::<Vec<_>>();
+        let other = other.iter().collect::<Vec<_>>();
+
+        this.as_slice().diff_with(other.as_slice(), options)
+    }
+}
+
+impl<T> Diffable<LinkedList<T>> for LinkedList<T>
+where
+    T: Clone + Hash + Ord + fmt::Debug,
+{
+    const KIND: DiffKind = DiffKind::Slice;
+
+    fn diff(&self, other: LinkedList<T>) -> Diff {
+        <&LinkedList<T>>::diff(&self, &other)
+    }
+
+    fn diff_with(&self, other: LinkedList<T>, options: &DiffOptions) -> Diff {
+        <&LinkedList<T>>::diff_with(&self, &other, options)
+    }
+}
+
+// The iteration order of a `BinaryHeap` is arbitrary, so we diff the elements in sorted order.
+impl<T> Diffable<&BinaryHeap<T>> for &BinaryHeap<T>
+where
+    T: Clone + Hash + Ord + fmt::Debug,
+{
+    const KIND: DiffKind = DiffKind::Slice;
+
+    fn diff(&self, other: &BinaryHeap<T>) -> Diff {
+        self.diff_with(other, &DiffOptions::default())
+    }
+
+    fn diff_with(&self, other: &BinaryHeap<T>, options: &DiffOptions) -> Diff {
+        let mut this = self.iter().collect::<Vec<_>>();
+        let mut other = other.iter().collect::<Vec<_>>();
+
+        this.sort();
+        other.sort();
+
+        this.as_slice().diff_with(other.as_slice(), options)
+    }
+}
+
+impl<T> Diffable<BinaryHeap<T>> for BinaryHeap<T>
+where
+    T: Clone + Hash + Ord + fmt::Debug,
+{
+    const KIND: DiffKind = DiffKind::Slice;
+
+    fn diff(&self, other: BinaryHeap<T>) -> Diff {
+        <&BinaryHeap<T>>::diff(&self, &other)
+    }
+
+    fn diff_with(&self, other: BinaryHeap<T>, options: &DiffOptions) -> Diff {
+        <&BinaryHeap<T>>::diff_with(&self, &other, options)
+    }
+}
+
+impl<T, U> Diffable<Option<U>> for Option<T>
+where
+    T: Diffable<U> + fmt::Debug,
+    U: fmt::Debug,
+{
+    const KIND: DiffKind = T::KIND;
+
+    fn diff(&self, other: Option<U>) -> Diff {
+        self.diff_with(other, &DiffOptions::default())
+    }
+
+    fn diff_with(&self, other: Option<U>, options: &DiffOptions) -> Diff {
+        match (self, other) {
+            (Some(this), Some(other)) => this.diff_with(other, options),
+            (None, None) => vec![DiffSegment::from_debug(None::<U>, DiffTag::Equal)],
+            // There's nothing inside `None` to compare against, so show each value whole. This is
+            // what lets `nest_diff` show a field like `address: None` instead of nesting into it.
+            (this, other) => vec![
+                DiffSegment::from_debug(this, DiffTag::Delete),
+                DiffSegment::from_debug(other, DiffTag::Insert),
+            ],
+        }
+    }
+}
+
+impl<T, U> Diffable<Box<U>> for Box<T>
+where
+    T: Diffable<U>,
+{
+    const KIND: DiffKind = T::KIND;
+
+    fn diff(&self, other: Box<U>) -> Diff {
+        T::diff(self, *other)
+    }
+
+    fn diff_with(&self, other: Box<U>, options: &DiffOptions) -> Diff {
+        T::diff_with(self, *other, options)
+    }
+}
+
+impl<T, U> Diffable<Rc<U>> for Rc<T>
+where
+    T: Diffable<U>,
+    U: Clone,
+{
+    const KIND: DiffKind = T::KIND;
+
+    fn diff(&self, other: Rc<U>) -> Diff {
+        T::diff(self, U::clone(&other))
+    }
+
+    fn diff_with(&self, other: Rc<U>, options: &DiffOptions) -> Diff {
+        T::diff_with(self, U::clone(&other), options)
+    }
+}
+
+impl<T, U> Diffable<Arc<U>> for Arc<T>
+where
+    T: Diffable<U>,
+    U: Clone,
+{
+    const KIND: DiffKind = T::KIND;
+
+    fn diff(&self, other: Arc<U>) -> Diff {
+        T::diff(self, U::clone(&other))
+    }
+
+    fn diff_with(&self, other: Arc<U>, options: &DiffOptions) -> Diff {
+        T::diff_with(self, U::clone(&other), options)
+    }
+}
+
+// Tuples are diffed like structs, with each element named by its index.
+macro_rules! impl_diffable_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name),+> Diffable<($($name,)+)> for ($($name,)+)
+        where
+            $($name: PartialEq + fmt::Debug,)+
+        {
+            const KIND: DiffKind = DiffKind::Map;
+
+            fn diff(&self, other: ($($name,)+)) -> Diff {
+                let mut segments = Vec::new();
+                $(segments.extend(diff_field(stringify!($index), &self.$index, &other.$index));)+
+                segments
+            }
+        }
+    };
+}
+
+impl_diffable_tuple!(A 0);
+impl_diffable_tuple!(A 0, B 1);
+impl_diffable_tuple!(A 0, B 1, C 2);
+impl_diffable_tuple!(A 0, B 1, C 2, D 3);
+impl_diffable_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_diffable_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+impl<T, U> Diffable<DebugDiff<U>> for DebugDiff<T>
+where
+    T: fmt::Debug,
+    U: fmt::Debug,
+{
+    const KIND: DiffKind = DiffKind::Line;
+
+    fn diff(&self, other: DebugDiff<U>) -> Diff {
+        self.diff_with(other, &DiffOptions::default())
+    }
+
+    fn diff_with(&self, other: DebugDiff<U>, options: &DiffOptions) -> Diff {
+        let expected = format!("{:#?}", self.0);
+        let actual = format!("{:#?}", other.0);
+
+        diff_lines(&expected, &actual, options)
+    }
+}
+
+/// The number of hex digits in the offset at the start of each row of a [`DiffKind::Bytes`] diff.
+const OFFSET_WIDTH: usize = 8;
+
+/// A part of a row of a [`DiffKind::Bytes`] diff.
+#[cfg(feature = "fmt")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HexCell {
+    /// The offset of the first byte in the row.
+    Offset,
+
+    /// The whitespace and delimiters between the other parts.
+    Separator,
+
+    /// The byte in this column as two hex digits, or blank if there's a gap in this column.
+    Byte(usize),
+
+    /// The byte in this column as an ASCII character, or blank if there's a gap in this column.
+    Char(usize),
+}
+
+/// Return the separator before the byte in the given column of a hex dump.
+fn hex_separator(column: usize) -> &'static str {
+    // Like `hexdump -C`, separate the offset and the two halves of the row with two spaces.
+    if column == 0 || column == BYTES_PER_ROW / 2 {
+        "  "
+    } else {
+        " "
+    }
+}
+
+/// Render a row of a [`DiffKind::Bytes`] diff, where `None` is a gap in that column.
+fn hex_row(offset: usize, columns: &[Option<u8>]) -> String {
+    let mut row = format!("{:0width$x}", offset, width = OFFSET_WIDTH);
+
+    for column in 0..BYTES_PER_ROW {
+        row.push_str(hex_separator(column));
+
+        match columns.get(column) {
+            Some(Some(byte)) => row.push_str(&format!("{:02x}", byte)),
+            _ => row.push_str("  "),
+        }
+    }
+
+    row.push_str("  |");
+
+    for column in columns {
+        row.push(match column {
+            Some(byte) if byte.is_ascii_graphic() || *byte == b' ' => *byte as char,
+            Some(_) => '.',
+            None => ' ',
+        });
+    }
+
+    row.push('|');
+    row
+}
+
+/// Split a row of a [`DiffKind::Bytes`] diff into its parts, in order.
+///
+/// This is the inverse of [`hex_row`], so formatters can style each byte without having to parse
+/// the row.
+#[cfg(feature = "fmt")]
+pub(crate) fn hex_row_cells(row: &str) -> Vec<(HexCell, &str)> {
+    let ascii_start = OFFSET_WIDTH
+        + (0..BYTES_PER_ROW)
+            .map(|column| hex_separator(column).len() + 2)
+            .sum::<usize>()
+        + "  |".len();
+
+    // Every char but the closing `|` is a column.
+    let ascii = row
+        .get(ascii_start..)
+        .unwrap_or_default()
+        .chars()
+        .collect::<Vec<_>>();
+
+    let mut cells = Vec::new();
+    let mut start = 0;
+
+    let mut take = |cell: HexCell, len: usize| {
+        let end = (start + len).min(row.len());
+
+        if let Some(text) = row.get(start..end).filter(|text| !text.is_empty()) {
+            cells.push((cell, text));
+        }
+
+        start = end;
+    };
+
+    take(HexCell::Offset, OFFSET_WIDTH);
+
+    for column in 0..BYTES_PER_ROW {
+        take(HexCell::Separator, hex_separator(column).len());
+        take(HexCell::Byte(column), 2);
+    }
+
+    take(HexCell::Separator, "  |".len());
+
+    for (column, c) in ascii.iter().take(ascii.len().saturating_sub(1)).enumerate() {
+        take(HexCell::Char(column), c.len_utf8());
+    }
+
+    take(HexCell::Separator, row.len());
+
+    cells
+}
+
+/// Align the bytes of two buffers by diffing them byte by byte.
+///
+/// Each column holds the byte in the expected buffer and the byte in the actual buffer at that
+/// position. Unchanged bytes share a column, and so do replaced bytes and the bytes that replaced
+/// them. Bytes which were only deleted or inserted leave a gap on the other side.
+fn align_bytes(
+    expected: &[u8],
+    actual: &[u8],
+    options: &DiffOptions,
+) -> Vec<(Option<u8>, Option<u8>)> {
+    let mut columns = Vec::with_capacity(cmp::max(expected.len(), actual.len()));
+
+    for op in capture_diff_slices(options.algorithm.to_similar(), expected, actual) {
+        let (old_range, new_range) = (op.old_range(), op.new_range());
+        let len = cmp::max(old_range.len(), new_range.len());
+
+        for index in 0..len {
+            let old = old_range
+                .clone()
+                .nth(index)
+                .map(|old_index| expected[old_index]);
+            let new = new_range
+                .clone()
+                .nth(index)
+                .map(|new_index| actual[new_index]);
+
+            columns.push((old, new));
+        }
+    }
+
+    columns
+}
+
+impl<T, U> Diffable<BytesDiff<U>> for BytesDiff<T>
+where
+    T: AsRef<[u8]>,
+    U: AsRef<[u8]>,
+{
+    const KIND: DiffKind = DiffKind::Bytes;
+
+    fn diff(&self, other: BytesDiff<U>) -> Diff {
+        self.diff_with(other, &DiffOptions::default())
+    }
+
+    fn diff_with(&self, other: BytesDiff<U>, options: &DiffOptions) -> Diff {
+        let columns = align_bytes(self.0.as_ref(), other.0.as_ref(), options);
+
+        let mut diff = Vec::new();
+        let (mut expected_offset, mut actual_offset) = (0, 0);
+
+        for row in columns.chunks(BYTES_PER_ROW) {
+            let expected_row = row.iter().map(|(old, _)| *old).collect::<Vec<_>>();
+            let actual_row = row.iter().map(|(_, new)| *new).collect::<Vec<_>>();
+
+            let expected_len = expected_row.iter().flatten().count();
+            let actual_len = actual_row.iter().flatten().count();
+
+            if expected_row == actual_row {
+                diff.push(DiffSegment {
+                    value: hex_row(expected_offset, &expected_row),
+                    tag: DiffTag::Equal,
+                });
+            } else {
+                if expected_len > 0 {
+                    diff.push(DiffSegment {
+                        value: hex_row(expected_offset, &expected_row),
+                        tag: DiffTag::Delete,
+                    });
+                }
+
+                if actual_len > 0 {
+                    diff.push(DiffSegment {
+                        value: hex_row(actual_offset, &actual_row),
+                        tag: DiffTag::Insert,
+                    });
+                }
+            }
+
+            expected_offset += expected_len;
+            actual_offset += actual_len;
+        }
+
+        diff
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::matchers::diff::DiffAlgorithm;
 
     #[test]
     fn diff_identical_strings() {
@@ -596,21 +1162,26 @@ mod tests {
     }
 
     #[test]
-    fn diff_identical_slices() {
-        let actual = ["a", "b", "c"].diff(["a", "b", "c"]);
+    fn diff_strings_by_word() {
+        let options = DiffOptions {
+            granularity: DiffGranularity::Word,
+            ..Default::default()
+        };
+
+        let actual = "foo bar".diff_with("foo baz", &options);
 
         let expected = vec![
             DiffSegment {
-                value: String::from("\"a\""),
+                value: "foo ".to_string(),
                 tag: DiffTag::Equal,
             },
             DiffSegment {
-                value: String::from("\"b\""),
-                tag: DiffTag::Equal,
+                value: "bar".to_string(),
+                tag: DiffTag::Delete,
             },
             DiffSegment {
-                value: String::from("\"c\""),
-                tag: DiffTag::Equal,
+                value: "baz".to_string(),
+                tag: DiffTag::Insert,
             },
         ];
 
@@ -618,16 +1189,25 @@ mod tests {
     }
 
     #[test]
-    fn diff_slices_with_addition() {
-        let actual = ["a"].diff(["a", "b"]);
+    fn diff_strings_by_line() {
+        let options = DiffOptions {
+            granularity: DiffGranularity::Line,
+            ..Default::default()
+        };
+
+        let actual = "foo\nbar\n".diff_with("foo\nbaz\n", &options);
 
         let expected = vec![
             DiffSegment {
-                value: String::from("\"a\""),
+                value: "foo".to_string(),
                 tag: DiffTag::Equal,
             },
             DiffSegment {
-                value: String::from("\"b\""),
+                value: "bar".to_string(),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: "baz".to_string(),
                 tag: DiffTag::Insert,
             },
         ];
@@ -636,23 +1216,140 @@ mod tests {
     }
 
     #[test]
-    fn diff_slices_with_deletion() {
-        let actual = ["a", "b"].diff(["a"]);
+    fn diff_strings_by_line_with_missing_trailing_newline() {
+        let options = DiffOptions {
+            granularity: DiffGranularity::Line,
+            ..Default::default()
+        };
+
+        let actual = "foo\nbar\n".diff_with("foo\nbar", &options);
 
         let expected = vec![
             DiffSegment {
-                value: String::from("\"a\""),
+                value: "foo".to_string(),
                 tag: DiffTag::Equal,
             },
             DiffSegment {
-                value: String::from("\"b\""),
+                value: "bar".to_string(),
                 tag: DiffTag::Delete,
             },
-        ];
-
-        assert_eq!(actual, expected);
-    }
-
+            DiffSegment {
+                value: "bar".to_string(),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: NO_NEWLINE_MARKER.to_string(),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_strings_by_line_without_trailing_newlines() {
+        let options = DiffOptions {
+            granularity: DiffGranularity::Line,
+            ..Default::default()
+        };
+
+        let actual = "foo\nbar".diff_with("foo\nbaz", &options);
+
+        assert!(actual
+            .iter()
+            .all(|segment| segment.value != NO_NEWLINE_MARKER));
+    }
+
+    #[test]
+    fn diff_sequences_with_algorithm() {
+        let before = ["a", "b", "c", "a", "b", "b", "a"];
+        let after = ["c", "b", "a", "b", "a", "c"];
+
+        for algorithm in [
+            DiffAlgorithm::Myers,
+            DiffAlgorithm::Patience,
+            DiffAlgorithm::Lcs,
+        ] {
+            let options = DiffOptions {
+                algorithm,
+                ..Default::default()
+            };
+
+            let expected = capture_diff_slices(algorithm.to_similar(), &before, &after)
+                .into_iter()
+                .flat_map(|op| op.iter_changes(&before, &after))
+                .map(|change| {
+                    DiffSegment::from_debug(change.value(), DiffTag::from_tag(change.tag()))
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(before.diff_with(after, &options), expected);
+
+            let before_deque = before.into_iter().collect::<VecDeque<_>>();
+            let after_deque = after.into_iter().collect::<VecDeque<_>>();
+
+            assert_eq!(before_deque.diff_with(after_deque, &options), expected);
+        }
+    }
+
+    #[test]
+    fn diff_identical_slices() {
+        let actual = ["a", "b", "c"].diff(["a", "b", "c"]);
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("\"a\""),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("\"b\""),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("\"c\""),
+                tag: DiffTag::Equal,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_slices_with_addition() {
+        let actual = ["a"].diff(["a", "b"]);
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("\"a\""),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("\"b\""),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_slices_with_deletion() {
+        let actual = ["a", "b"].diff(["a"]);
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("\"a\""),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("\"b\""),
+                tag: DiffTag::Delete,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn diff_slices_with_addition_and_deletion() {
         let actual = ["a", "b"].diff(["b", "c"]);
@@ -865,4 +1562,423 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn diff_vec_deques() {
+        let before = [1, 2].into_iter().collect::<VecDeque<_>>();
+        let after = [1, 3].into_iter().collect::<VecDeque<_>>();
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("1"),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("2"),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("3"),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(before.diff(after), expected);
+    }
+
+    #[test]
+    fn diff_binary_heaps_in_sorted_order() {
+        let before = [3, 1].into_iter().collect::<BinaryHeap<_>>();
+        let after = [1, 2, 3].into_iter().collect::<BinaryHeap<_>>();
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("1"),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("2"),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: String::from("3"),
+                tag: DiffTag::Equal,
+            },
+        ];
+
+        assert_eq!(before.diff(after), expected);
+    }
+
+    #[test]
+    fn diff_options() {
+        assert_eq!(Some("foo").diff(Some("foo")), "foo".diff("foo"));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("Some(\"foo\")"),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("None"),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(Some("foo").diff(None::<&str>), expected);
+
+        let expected = vec![DiffSegment {
+            value: String::from("None"),
+            tag: DiffTag::Equal,
+        }];
+
+        assert_eq!(None::<&str>.diff(None::<&str>), expected);
+    }
+
+    #[test]
+    fn diff_smart_pointers() {
+        assert_eq!(Box::new("foo").diff(Box::new("bar")), "foo".diff("bar"));
+        assert_eq!(Rc::new("foo").diff(Rc::new("bar")), "foo".diff("bar"));
+        assert_eq!(Arc::new("foo").diff(Arc::new("bar")), "foo".diff("bar"));
+    }
+
+    #[test]
+    fn diff_tuples() {
+        let expected = vec![
+            DiffSegment {
+                value: String::from("0: \"a\""),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("1: 1"),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("1: 2"),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(("a", 1).diff(("a", 2)), expected);
+    }
+
+    #[test]
+    fn diff_identical_debug_values() {
+        let actual = DebugDiff(Some(1)).diff(DebugDiff(Some(1)));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("Some("),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("    1,"),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from(")"),
+                tag: DiffTag::Equal,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_debug_values_with_changed_line() {
+        let actual = DebugDiff(("a", 1)).diff(DebugDiff(("a", 2)));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("("),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("    \"a\","),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from("    1,"),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("    2,"),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: String::from(")"),
+                tag: DiffTag::Equal,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_bytes_by_row() {
+        let expected_bytes = (0..40).collect::<Vec<u8>>();
+        let mut actual_bytes = expected_bytes.clone();
+        actual_bytes[20] = 0xff;
+        actual_bytes.truncate(34);
+
+        let actual = BytesDiff(expected_bytes).diff(BytesDiff(actual_bytes));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from(
+                    "00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  |................|",
+                ),
+                tag: DiffTag::Equal,
+            },
+            DiffSegment {
+                value: String::from(
+                    "00000010  10 11 12 13 14 15 16 17  18 19 1a 1b 1c 1d 1e 1f  |................|",
+                ),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from(
+                    "00000010  10 11 12 13 ff 15 16 17  18 19 1a 1b 1c 1d 1e 1f  |................|",
+                ),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: String::from(
+                    "00000020  20 21 22 23 24 25 26 27                           | !\"#$%&'|",
+                ),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from(
+                    "00000020  20 21                                             | !      |",
+                ),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_bytes_with_insertion() {
+        let expected_bytes = b"abcdefghijklmnopqrstuvwxyz".to_vec();
+        let mut actual_bytes = expected_bytes.clone();
+        actual_bytes.insert(2, b'!');
+
+        let actual = BytesDiff(expected_bytes).diff(BytesDiff(actual_bytes));
+
+        // Only the row with the inserted byte changes. The rows after it are aligned with the
+        // expected value instead of being shifted by one byte.
+        let expected = vec![
+            DiffSegment {
+                value: String::from(
+                    "00000000  61 62    63 64 65 66 67  68 69 6a 6b 6c 6d 6e 6f  |ab cdefghijklmno|",
+                ),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from(
+                    "00000000  61 62 21 63 64 65 66 67  68 69 6a 6b 6c 6d 6e 6f  |ab!cdefghijklmno|",
+                ),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: String::from(
+                    "0000000f  70 71 72 73 74 75 76 77  78 79 7a                 |pqrstuvwxyz|",
+                ),
+                tag: DiffTag::Equal,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "fmt")]
+    fn split_hex_rows_into_cells() {
+        let row = hex_row(0x10, &[Some(b'a'), None]);
+        let cells = hex_row_cells(&row);
+
+        assert_eq!(cells.iter().map(|(_, text)| *text).collect::<String>(), row);
+        assert_eq!(cells[0], (HexCell::Offset, "00000010"));
+        assert!(cells.contains(&(HexCell::Byte(0), "61")));
+        assert!(cells.contains(&(HexCell::Byte(1), "  ")));
+        assert!(cells.contains(&(HexCell::Byte(15), "  ")));
+        assert!(cells.contains(&(HexCell::Char(0), "a")));
+        assert!(cells.contains(&(HexCell::Char(1), " ")));
+        assert!(!cells
+            .iter()
+            .any(|(cell, _)| matches!(cell, HexCell::Char(2))));
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod derive_tests {
+    use crate::matchers::diff::{DiffSegment, DiffTag, Diffable};
+
+    #[derive(Debug, PartialEq, Diffable)]
+    struct Address {
+        city: &'static str,
+    }
+
+    #[derive(Debug, PartialEq, Diffable)]
+    struct Person {
+        name: &'static str,
+        id: u32,
+        #[diffable(nested)]
+        address: Address,
+    }
+
+    #[derive(Debug, PartialEq, Diffable)]
+    enum Shape {
+        Circle(u32),
+        Square { side: u32 },
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq, Diffable)]
+    struct Drawing {
+        #[diffable(nested)]
+        shape: Shape,
+        #[diffable(nested)]
+        label: Option<Address>,
+        #[diffable(nested)]
+        tags: Vec<&'static str>,
+    }
+
+    fn segment(value: &str, tag: DiffTag) -> DiffSegment {
+        DiffSegment {
+            value: String::from(value),
+            tag,
+        }
+    }
+
+    #[test]
+    fn diff_derived_structs() {
+        let before = Person {
+            name: "Kim",
+            id: 57,
+            address: Address { city: "Revachol" },
+        };
+
+        let after = Person {
+            name: "Kim",
+            id: 41,
+            address: Address { city: "Jamrock" },
+        };
+
+        let expected = vec![
+            segment("name: \"Kim\"", DiffTag::Equal),
+            segment("id: 57", DiffTag::Delete),
+            segment("id: 41", DiffTag::Insert),
+            segment("address.city: \"Revachol\"", DiffTag::Delete),
+            segment("address.city: \"Jamrock\"", DiffTag::Insert),
+        ];
+
+        assert_eq!(before.diff(after), expected);
+    }
+
+    #[test]
+    fn diff_derived_enums_with_same_variant() {
+        let expected = vec![
+            segment("0: 1", DiffTag::Delete),
+            segment("0: 2", DiffTag::Insert),
+        ];
+
+        assert_eq!(Shape::Circle(1).diff(Shape::Circle(2)), expected);
+
+        let expected = vec![segment("side: 1", DiffTag::Equal)];
+
+        assert_eq!(
+            Shape::Square { side: 1 }.diff(Shape::Square { side: 1 }),
+            expected
+        );
+
+        let expected = vec![segment("Empty", DiffTag::Equal)];
+
+        assert_eq!(Shape::Empty.diff(Shape::Empty), expected);
+    }
+
+    #[test]
+    fn diff_derived_enums_with_different_variants() {
+        let expected = vec![
+            segment("Circle(1)", DiffTag::Delete),
+            segment("Square { side: 1 }", DiffTag::Insert),
+        ];
+
+        assert_eq!(Shape::Circle(1).diff(Shape::Square { side: 1 }), expected);
+    }
+
+    #[test]
+    fn diff_nested_fields_which_are_not_maps() {
+        let before = Drawing {
+            shape: Shape::Empty,
+            label: None,
+            tags: vec!["a", "b"],
+        };
+
+        let after = Drawing {
+            shape: Shape::Empty,
+            label: None,
+            tags: vec!["a", "c"],
+        };
+
+        let expected = vec![
+            segment("shape: Empty", DiffTag::Equal),
+            segment("label: None", DiffTag::Equal),
+            segment("tags: [\"a\", \"b\"]", DiffTag::Delete),
+            segment("tags: [\"a\", \"c\"]", DiffTag::Insert),
+        ];
+
+        assert_eq!(before.diff(after), expected);
+    }
+
+    #[test]
+    fn diff_nested_options() {
+        let before = Drawing {
+            shape: Shape::Circle(1),
+            label: Some(Address { city: "Revachol" }),
+            tags: Vec::new(),
+        };
+
+        let after = Drawing {
+            shape: Shape::Square { side: 1 },
+            label: None,
+            tags: Vec::new(),
+        };
+
+        let expected = vec![
+            segment("shape: Circle(1)", DiffTag::Delete),
+            segment("shape: Square { side: 1 }", DiffTag::Insert),
+            segment(
+                "label: Some(Address { city: \"Revachol\" })",
+                DiffTag::Delete,
+            ),
+            segment("label: None", DiffTag::Insert),
+            segment("tags: []", DiffTag::Equal),
+        ];
+
+        assert_eq!(before.diff(after), expected);
+
+        let before = Drawing {
+            shape: Shape::Circle(1),
+            label: Some(Address { city: "Revachol" }),
+            tags: Vec::new(),
+        };
+
+        let after = Drawing {
+            shape: Shape::Circle(2),
+            label: Some(Address { city: "Jamrock" }),
+            tags: Vec::new(),
+        };
+
+        let expected = vec![
+            segment("shape.0: 1", DiffTag::Delete),
+            segment("shape.0: 2", DiffTag::Insert),
+            segment("label.city: \"Revachol\"", DiffTag::Delete),
+            segment("label.city: \"Jamrock\"", DiffTag::Insert),
+            segment("tags: []", DiffTag::Equal),
+        ];
+
+        assert_eq!(before.diff(after), expected);
+    }
 }
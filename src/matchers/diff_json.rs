@@ -0,0 +1,119 @@
+use std::borrow::Cow;
+
+use serde_json::Value as JsonValue;
+
+use super::diff::{Diff, DiffKind, Diffable};
+use super::diff_path::{diff_tree, Children, PathKey, TreeValue};
+
+impl TreeValue for JsonValue {
+    fn children(&self) -> Option<Children<'_, Self>> {
+        match self {
+            JsonValue::Object(map) => Some(Children::Map(
+                map.iter()
+                    .map(|(key, value)| (PathKey::Str(Cow::Borrowed(key.as_str())), value))
+                    .collect(),
+            )),
+            JsonValue::Array(vec) => Some(Children::Seq(vec)),
+            _ => None,
+        }
+    }
+
+    fn render(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Diffable<&JsonValue> for &JsonValue {
+    const KIND: DiffKind = DiffKind::KeyPath;
+
+    fn diff(&self, other: &JsonValue) -> Diff {
+        diff_tree(*self, other)
+    }
+}
+
+impl Diffable<JsonValue> for JsonValue {
+    const KIND: DiffKind = DiffKind::KeyPath;
+
+    fn diff(&self, other: JsonValue) -> Diff {
+        diff_tree(self, &other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::matchers::diff::{DiffSegment, DiffTag};
+
+    #[test]
+    fn diff_identical_values() {
+        let actual = json!({ "a": 1 }).diff(json!({ "a": 1 }));
+
+        let expected = vec![DiffSegment {
+            value: String::from(r#"$: {"a":1}"#),
+            tag: DiffTag::Equal,
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_values_with_changed_key() {
+        let actual = json!({ "user": { "roles": ["a", "b", "admin"] } })
+            .diff(json!({ "user": { "roles": ["a", "b", "user"] } }));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from(r#"$.user.roles[2]: "admin""#),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from(r#"$.user.roles[2]: "user""#),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_values_with_addition_and_deletion() {
+        let actual = json!({ "a": 1, "b": [1] }).diff(json!({ "b": [1, 2], "the c": null }));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("$.a: 1"),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("$.b[1]: 2"),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: String::from(r#"$["the c"]: null"#),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_values_with_different_types() {
+        let actual = json!({ "a": [1] }).diff(json!({ "a": { "0": 1 } }));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("$.a: [1]"),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from(r#"$.a: {"0":1}"#),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
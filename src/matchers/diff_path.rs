@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use super::diff::{Diff, DiffSegment, DiffTag};
+
+pub(crate) const ROOT_PATH: &str = "$";
+
+/// The key of a value in a map.
+///
+/// String keys and other keys are kept apart, so that the string `"1"` and the number `1` are
+/// different keys with different paths, even in formats like YAML which allow both.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum PathKey<'a> {
+    /// A string key.
+    Str(Cow<'a, str>),
+
+    /// Any other key, rendered on a single line.
+    #[cfg(feature = "yaml")]
+    Other(String),
+}
+
+/// The children of a value in a tree of structured data.
+pub(crate) enum Children<'a, T> {
+    /// The value is a map of keys to values, in the order they should be diffed.
+    Map(Vec<(PathKey<'a>, &'a T)>),
+
+    /// The value is a sequence of values.
+    Seq(&'a [T]),
+}
+
+/// A value in a tree of structured data, like a parsed JSON, YAML, or TOML document.
+pub(crate) trait TreeValue: PartialEq + Sized {
+    /// Return the children of this value, or `None` if it's a scalar.
+    fn children(&self) -> Option<Children<'_, Self>>;
+
+    /// Render this value on a single line.
+    fn render(&self) -> String;
+}
+
+/// Returns whether `key` can be written in a path using dot notation.
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+
+    match chars.next() {
+        Some(first) if first.is_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Quote `value` as a JSON string.
+pub(crate) fn quote_json(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+
+    quoted.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\u{c}' => quoted.push_str("\\f"),
+            c if (c as u32) < 0x20 => {
+                write!(quoted, "\\u{:04x}", c as u32).expect("writing to a string can't fail");
+            }
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+
+    quoted
+}
+
+/// Returns the path of the value at the string `key` in the map at `parent`.
+///
+/// Keys which are identifiers use dot notation, and other keys use bracket notation with the key
+/// quoted as a JSON string, like `$["the key"]`.
+pub(crate) fn key_path(parent: &str, key: &str) -> String {
+    if is_identifier(key) {
+        format!("{}.{}", parent, key)
+    } else {
+        format!("{}[{}]", parent, quote_json(key))
+    }
+}
+
+/// Returns the path of the value at `key` in the map at `parent`.
+///
+/// Keys which aren't strings are written in bracket notation without quotes, like `$[1]`, so they
+/// can't be confused with string keys.
+fn map_key_path(parent: &str, key: &PathKey<'_>) -> String {
+    match key {
+        PathKey::Str(key) => key_path(parent, key),
+        #[cfg(feature = "yaml")]
+        PathKey::Other(key) => format!("{}[{}]", parent, key),
+    }
+}
+
+/// Returns the path of the value at `index` in the sequence at `parent`.
+pub(crate) fn index_path(parent: &str, index: usize) -> String {
+    format!("{}[{}]", parent, index)
+}
+
+fn segment<T: TreeValue>(path: &str, value: &T, tag: DiffTag) -> DiffSegment {
+    DiffSegment {
+        value: format!("{}: {}", path, value.render()),
+        tag,
+    }
+}
+
+fn diff_maps<T: TreeValue>(
+    path: &str,
+    expected: Vec<(PathKey<'_>, &T)>,
+    actual: Vec<(PathKey<'_>, &T)>,
+    segments: &mut Diff,
+) {
+    let mut actual_by_key = actual
+        .iter()
+        .map(|(key, value)| (key, *value))
+        .collect::<HashMap<_, _>>();
+
+    for (key, expected_value) in &expected {
+        let child_path = map_key_path(path, key);
+
+        match actual_by_key.remove(key) {
+            Some(actual_value) => diff_values(&child_path, *expected_value, actual_value, segments),
+            None => segments.push(segment(&child_path, *expected_value, DiffTag::Delete)),
+        }
+    }
+
+    // Preserve the order of the keys that were only in the actual value.
+    for (key, actual_value) in &actual {
+        if actual_by_key.contains_key(key) {
+            segments.push(segment(
+                &map_key_path(path, key),
+                *actual_value,
+                DiffTag::Insert,
+            ));
+        }
+    }
+}
+
+fn diff_seqs<T: TreeValue>(path: &str, expected: &[T], actual: &[T], segments: &mut Diff) {
+    for index in 0..expected.len().max(actual.len()) {
+        let child_path = index_path(path, index);
+
+        match (expected.get(index), actual.get(index)) {
+            (Some(expected_value), Some(actual_value)) => {
+                diff_values(&child_path, expected_value, actual_value, segments)
+            }
+            (Some(expected_value), None) => {
+                segments.push(segment(&child_path, expected_value, DiffTag::Delete))
+            }
+            (None, Some(actual_value)) => {
+                segments.push(segment(&child_path, actual_value, DiffTag::Insert))
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+}
+
+fn diff_values<T: TreeValue>(path: &str, expected: &T, actual: &T, segments: &mut Diff) {
+    match (expected.children(), actual.children()) {
+        (Some(Children::Map(expected_map)), Some(Children::Map(actual_map))) => {
+            diff_maps(path, expected_map, actual_map, segments)
+        }
+        (Some(Children::Seq(expected_seq)), Some(Children::Seq(actual_seq))) => {
+            diff_seqs(path, expected_seq, actual_seq, segments)
+        }
+        _ if expected == actual => {}
+        _ => {
+            segments.push(segment(path, expected, DiffTag::Delete));
+            segments.push(segment(path, actual, DiffTag::Insert));
+        }
+    }
+}
+
+/// Diff two trees of structured data by the path of each value from the root.
+///
+/// Rather than diffing the serialized text, this walks both values in parallel and records each
+/// value that differs along with its path, like `$.user.roles[2]`. A value that changed is
+/// represented as a deletion of the expected value followed by an insertion of the actual value at
+/// the same path. If the two values are equal, the diff is a single segment containing the whole
+/// value.
+pub(crate) fn diff_tree<T: TreeValue>(expected: &T, actual: &T) -> Diff {
+    let mut segments = Vec::new();
+
+    diff_values(ROOT_PATH, expected, actual, &mut segments);
+
+    if segments.is_empty() {
+        segments.push(segment(ROOT_PATH, expected, DiffTag::Equal));
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{index_path, key_path, quote_json};
+
+    #[test]
+    fn quotes_strings_with_json_escapes() {
+        assert_eq!(quote_json("plain"), r#""plain""#);
+        assert_eq!(quote_json("say \"hi\"\n"), r#""say \"hi\"\n""#);
+        assert_eq!(quote_json("tab\there"), r#""tab\there""#);
+        assert_eq!(quote_json("\u{1}"), r#""\u0001""#);
+        assert_eq!(quote_json("café"), r#""café""#);
+    }
+
+    #[test]
+    fn uses_dot_notation_for_identifiers() {
+        assert_eq!(key_path("$", "user"), "$.user");
+        assert_eq!(index_path("$.user", 2), "$.user[2]");
+    }
+
+    #[test]
+    fn uses_json_strings_in_bracket_notation() {
+        assert_eq!(key_path("$", "the key"), r#"$["the key"]"#);
+        assert_eq!(key_path("$", "1"), r#"$["1"]"#);
+        assert_eq!(key_path("$", "a\"b"), r#"$["a\"b"]"#);
+    }
+}
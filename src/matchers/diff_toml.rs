@@ -0,0 +1,106 @@
+use std::borrow::Cow;
+
+use toml::Value as TomlValue;
+
+use super::diff::{Diff, DiffKind, Diffable};
+use super::diff_path::{diff_tree, Children, PathKey, TreeValue};
+
+impl TreeValue for TomlValue {
+    fn children(&self) -> Option<Children<'_, Self>> {
+        match self {
+            TomlValue::Table(table) => Some(Children::Map(
+                table
+                    .iter()
+                    .map(|(key, value)| (PathKey::Str(Cow::Borrowed(key.as_str())), value))
+                    .collect(),
+            )),
+            TomlValue::Array(array) => Some(Children::Seq(array)),
+            _ => None,
+        }
+    }
+
+    fn render(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Diffable<&TomlValue> for &TomlValue {
+    const KIND: DiffKind = DiffKind::KeyPath;
+
+    fn diff(&self, other: &TomlValue) -> Diff {
+        diff_tree(*self, other)
+    }
+}
+
+impl Diffable<TomlValue> for TomlValue {
+    const KIND: DiffKind = DiffKind::KeyPath;
+
+    fn diff(&self, other: TomlValue) -> Diff {
+        diff_tree(self, &other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::from_str as toml_from_str;
+
+    use super::*;
+    use crate::matchers::diff::{DiffSegment, DiffTag};
+
+    fn toml(s: &str) -> TomlValue {
+        toml_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn diff_identical_values() {
+        let actual = toml("a = 1").diff(toml("a = 1"));
+
+        let expected = vec![DiffSegment {
+            value: String::from("$: { a = 1 }"),
+            tag: DiffTag::Equal,
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_values_with_changed_key() {
+        let actual = toml("[user]\nroles = [\"a\", \"admin\"]")
+            .diff(toml("[user]\nroles = [\"a\", \"user\"]"));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("$.user.roles[1]: \"admin\""),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("$.user.roles[1]: \"user\""),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_values_with_addition_and_deletion() {
+        let actual = toml("a = 1\nb = [1]").diff(toml("b = [1, 2]\n\"the c\" = true"));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("$.a: 1"),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("$.b[1]: 2"),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: String::from("$[\"the c\"]: true"),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+
+use serde_yaml::Value as YamlValue;
+
+use super::diff::{Diff, DiffKind, Diffable};
+use super::diff_path::{diff_tree, quote_json, Children, PathKey, TreeValue};
+
+/// Render a YAML value on a single line using flow style.
+fn render(value: &YamlValue) -> String {
+    match value {
+        YamlValue::Null => String::from("null"),
+        YamlValue::Bool(value) => value.to_string(),
+        YamlValue::Number(value) => value.to_string(),
+        YamlValue::String(value) => quote_json(value),
+        YamlValue::Sequence(seq) => {
+            let elements = seq.iter().map(render).collect::<Vec<_>>();
+            format!("[{}]", elements.join(", "))
+        }
+        YamlValue::Mapping(map) => {
+            let pairs = map
+                .iter()
+                .map(|(key, value)| format!("{}: {}", render(key), render(value)))
+                .collect::<Vec<_>>();
+            format!("{{{}}}", pairs.join(", "))
+        }
+        YamlValue::Tagged(tagged) => format!("{} {}", tagged.tag, render(&tagged.value)),
+    }
+}
+
+impl TreeValue for YamlValue {
+    fn children(&self) -> Option<Children<'_, Self>> {
+        match self {
+            YamlValue::Mapping(map) => Some(Children::Map(
+                map.iter()
+                    .map(|(key, value)| match key {
+                        YamlValue::String(key) => {
+                            (PathKey::Str(Cow::Borrowed(key.as_str())), value)
+                        }
+                        _ => (PathKey::Other(render(key)), value),
+                    })
+                    .collect(),
+            )),
+            YamlValue::Sequence(seq) => Some(Children::Seq(seq)),
+            _ => None,
+        }
+    }
+
+    fn render(&self) -> String {
+        render(self)
+    }
+}
+
+impl Diffable<&YamlValue> for &YamlValue {
+    const KIND: DiffKind = DiffKind::KeyPath;
+
+    fn diff(&self, other: &YamlValue) -> Diff {
+        diff_tree(*self, other)
+    }
+}
+
+impl Diffable<YamlValue> for YamlValue {
+    const KIND: DiffKind = DiffKind::KeyPath;
+
+    fn diff(&self, other: YamlValue) -> Diff {
+        diff_tree(self, &other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_yaml::from_str as yaml_from_str;
+
+    use super::*;
+    use crate::matchers::diff::{DiffSegment, DiffTag};
+
+    fn yaml(s: &str) -> YamlValue {
+        yaml_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn diff_identical_values() {
+        let actual = yaml("a: 1").diff(yaml("a: 1"));
+
+        let expected = vec![DiffSegment {
+            value: String::from("$: {\"a\": 1}"),
+            tag: DiffTag::Equal,
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_values_with_changed_key() {
+        let actual = yaml("user: {roles: [a, admin]}").diff(yaml("user: {roles: [a, user]}"));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("$.user.roles[1]: \"admin\""),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("$.user.roles[1]: \"user\""),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_values_with_addition_and_deletion() {
+        let actual = yaml("{a: 1, b: [1]}").diff(yaml("{b: [1, 2], the c: null}"));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("$.a: 1"),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("$.b[1]: 2"),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: String::from("$[\"the c\"]: null"),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_values_with_non_string_keys() {
+        let actual = yaml("{1: a, '1': b}").diff(yaml("{1: a, '1': c, true: d}"));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("$[\"1\"]: \"b\""),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("$[\"1\"]: \"c\""),
+                tag: DiffTag::Insert,
+            },
+            DiffSegment {
+                value: String::from("$[true]: \"d\""),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn string_and_number_keys_do_not_collide() {
+        let actual = yaml("{1: a}").diff(yaml("{'1': a}"));
+
+        let expected = vec![
+            DiffSegment {
+                value: String::from("$[1]: \"a\""),
+                tag: DiffTag::Delete,
+            },
+            DiffSegment {
+                value: String::from("$[\"1\"]: \"a\""),
+                tag: DiffTag::Insert,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
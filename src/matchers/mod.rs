@@ -32,6 +32,14 @@ pub mod default;
 pub mod diff;
 #[cfg(feature = "diff")]
 mod diff_impl;
+#[cfg(feature = "json")]
+mod diff_json;
+#[cfg(any(feature = "json", feature = "yaml", feature = "toml"))]
+mod diff_path;
+#[cfg(feature = "toml")]
+mod diff_toml;
+#[cfg(feature = "yaml")]
+mod diff_yaml;
 /// Matchers for comparing if two values are equal.
 pub mod equal;
 /// Matchers for making assertions about struct fields.
@@ -52,10 +60,13 @@ pub mod ord;
 pub mod pattern;
 /// Matchers for working with `Result` values.
 pub mod result;
+/// Matchers for serializing and deserializing values.
+#[cfg(feature = "json")]
+pub mod serialize;
 /// Matchers for working with strings.
 pub mod strings;
 /// Matchers for working with time.
 pub mod time;
 mod values;
 
-pub use values::{Expectation, FailuresByField, Mismatch, SomeFailures};
+pub use values::{Expectation, FailuresByField, Mismatch, SomeFailures, SomeOutcomes};
@@ -76,7 +76,7 @@ impl<'a, Actual> PatternMatcher<'a, Actual> {
     }
 }
 
-impl<'a, 'b: 'a, Actual> Match<Actual> for PatternMatcher<'a, Actual> {
+impl<'a, Actual> Match<Actual> for PatternMatcher<'a, Actual> {
     type Fail = Mismatch<Pattern<'a, Actual>, Actual>;
 
     fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
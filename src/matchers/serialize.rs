@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{from_str as json_from_str, Value as JsonValue};
+
+use crate::core::Match;
+use crate::matchers::diff::{DebugDiff, Diff, Diffable};
+
+/// The matcher for [`serialize_to_json`].
+///
+/// [`serialize_to_json`]: crate::serialize_to_json
+#[derive(Debug)]
+pub struct SerializeJsonMatcher<'a> {
+    expected_json: Cow<'a, str>,
+    parsed: Option<(JsonValue, JsonValue)>,
+}
+
+impl<'a> SerializeJsonMatcher<'a> {
+    /// Create a new [`SerializeJsonMatcher`] from the expected JSON string.
+    pub fn new(json: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            expected_json: json.into(),
+            parsed: None,
+        }
+    }
+}
+
+impl<'a, Actual> Match<Actual> for SerializeJsonMatcher<'a>
+where
+    Actual: Serialize,
+{
+    type Fail = Diff;
+
+    fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
+        let expected_value: JsonValue = json_from_str(&self.expected_json)?;
+        let actual_value = serde_json::to_value(actual)?;
+        let is_match = actual_value == expected_value;
+        self.parsed = Some((expected_value, actual_value));
+        Ok(is_match)
+    }
+
+    fn fail(self, _: Actual) -> Self::Fail {
+        // If the expected string is invalid JSON or the actual value can't be serialized, `matches`
+        // will return an error and this method will never be called. If something else is
+        // happening, it's a bug.
+        let (expected_value, actual_value) = self
+            .parsed
+            .expect("The JSON was never parsed, but the matcher failed. This shouldn't happen.");
+
+        expected_value.diff(actual_value)
+    }
+}
+
+/// The matcher for [`roundtrip_json`].
+///
+/// [`roundtrip_json`]: crate::roundtrip_json
+#[derive(Debug)]
+pub struct RoundtripJsonMatcher<Actual> {
+    roundtripped: Option<Actual>,
+}
+
+impl<Actual> RoundtripJsonMatcher<Actual> {
+    /// Create a new [`RoundtripJsonMatcher`].
+    pub fn new() -> Self {
+        Self { roundtripped: None }
+    }
+}
+
+impl<Actual> Default for RoundtripJsonMatcher<Actual> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Actual> Match<Actual> for RoundtripJsonMatcher<Actual>
+where
+    Actual: fmt::Debug + PartialEq + Serialize + DeserializeOwned,
+{
+    type Fail = Diff;
+
+    fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
+        let json = serde_json::to_string(actual)?;
+        let roundtripped: Actual = json_from_str(&json)?;
+        let is_match = &roundtripped == actual;
+        self.roundtripped = Some(roundtripped);
+        Ok(is_match)
+    }
+
+    fn fail(self, actual: Actual) -> Self::Fail {
+        // If the value can't be serialized or deserialized, `matches` will return an error and this
+        // method will never be called. If something else is happening, it's a bug.
+        let roundtripped = self.roundtripped.expect(
+            "The value was never round-tripped, but the matcher failed. This shouldn't happen.",
+        );
+
+        // Most types don't implement `Diffable`, so we diff their debug representations instead.
+        DebugDiff(actual).diff(DebugDiff(roundtripped))
+    }
+}
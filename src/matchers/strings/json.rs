@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use serde_json::{from_str as json_from_str, Value as JsonValue};
 
 use crate::core::Match;
-use crate::matchers::Mismatch;
+use crate::matchers::diff::{Diff, Diffable};
 
 /// The matcher for [`match_json`].
 ///
@@ -11,6 +11,7 @@ use crate::matchers::Mismatch;
 #[derive(Debug)]
 pub struct JsonMatcher<'a> {
     expected_json: Cow<'a, str>,
+    parsed: Option<(JsonValue, JsonValue)>,
 }
 
 impl<'a> JsonMatcher<'a> {
@@ -18,6 +19,7 @@ impl<'a> JsonMatcher<'a> {
     pub fn new(json: impl Into<Cow<'a, str>>) -> Self {
         Self {
             expected_json: json.into(),
+            parsed: None,
         }
     }
 }
@@ -26,18 +28,23 @@ impl<'a, Actual> Match<Actual> for JsonMatcher<'a>
 where
     Actual: AsRef<str>,
 {
-    type Fail = Mismatch<Cow<'a, str>, Actual>;
+    type Fail = Diff;
 
     fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
         let expected_value: JsonValue = json_from_str(&self.expected_json)?;
         let actual_value: JsonValue = json_from_str(actual.as_ref())?;
-        Ok(actual_value == expected_value)
+        let is_match = actual_value == expected_value;
+        self.parsed = Some((expected_value, actual_value));
+        Ok(is_match)
     }
 
-    fn fail(self, actual: Actual) -> Self::Fail {
-        Mismatch {
-            expected: self.expected_json,
-            actual,
-        }
+    fn fail(self, _: Actual) -> Self::Fail {
+        // If either string is invalid JSON, `matches` will return an error and this method will
+        // never be called. If something else is happening, it's a bug.
+        let (expected_value, actual_value) = self
+            .parsed
+            .expect("The JSON was never parsed, but the matcher failed. This shouldn't happen.");
+
+        expected_value.diff(actual_value)
     }
 }
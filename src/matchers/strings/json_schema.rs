@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+
+use jsonschema::paths::{JSONPointer, PathChunk};
+use jsonschema::{Draft, JSONSchema, ValidationError};
+use serde_json::{from_str as json_from_str, Value as JsonValue};
+
+use crate::core::Match;
+use crate::matchers::diff_path::{index_path, key_path, ROOT_PATH};
+
+/// A value which can be interpreted as JSON.
+///
+/// This is implemented for strings, which are parsed as JSON, and for [`serde_json::Value`].
+pub trait AsJson {
+    /// Return this value as JSON, or an error if it is not valid JSON.
+    fn as_json(&self) -> crate::Result<Cow<'_, JsonValue>>;
+}
+
+impl AsJson for str {
+    fn as_json(&self) -> crate::Result<Cow<'_, JsonValue>> {
+        Ok(Cow::Owned(json_from_str(self)?))
+    }
+}
+
+impl AsJson for String {
+    fn as_json(&self) -> crate::Result<Cow<'_, JsonValue>> {
+        self.as_str().as_json()
+    }
+}
+
+impl<'a> AsJson for Cow<'a, str> {
+    fn as_json(&self) -> crate::Result<Cow<'_, JsonValue>> {
+        self.as_ref().as_json()
+    }
+}
+
+impl AsJson for JsonValue {
+    fn as_json(&self) -> crate::Result<Cow<'_, JsonValue>> {
+        Ok(Cow::Borrowed(self))
+    }
+}
+
+impl<T> AsJson for &T
+where
+    T: AsJson + ?Sized,
+{
+    fn as_json(&self) -> crate::Result<Cow<'_, JsonValue>> {
+        T::as_json(self)
+    }
+}
+
+/// A single reason why a JSON value failed to validate against a JSON schema.
+///
+/// This is returned by [`JsonSchemaMatcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// The path of the invalid value from the root of the instance, like `$.user.roles[2]`.
+    pub instance_path: String,
+
+    /// The JSON pointer of the schema keyword that failed validation.
+    pub schema_path: String,
+
+    /// The schema keyword that failed validation, like `required` or `minimum`.
+    pub keyword: String,
+
+    /// A human-readable description of the error.
+    pub message: String,
+}
+
+fn instance_path(pointer: &JSONPointer) -> String {
+    let mut path = String::from(ROOT_PATH);
+
+    for chunk in pointer.iter() {
+        path = match chunk {
+            PathChunk::Property(key) => key_path(&path, key),
+            PathChunk::Index(index) => index_path(&path, *index),
+            PathChunk::Keyword(keyword) => key_path(&path, keyword),
+        };
+    }
+
+    path
+}
+
+impl<'a> From<ValidationError<'a>> for SchemaViolation {
+    fn from(error: ValidationError<'a>) -> Self {
+        let keyword = match error.schema_path.last() {
+            Some(PathChunk::Keyword(keyword)) => keyword.to_string(),
+            Some(PathChunk::Property(keyword)) => keyword.to_string(),
+            Some(PathChunk::Index(_)) | None => error.schema_path.to_string(),
+        };
+
+        Self {
+            instance_path: instance_path(&error.instance_path),
+            schema_path: error.schema_path.to_string(),
+            keyword,
+            message: error.to_string(),
+        }
+    }
+}
+
+/// The matcher for [`match_json_schema`].
+///
+/// [`match_json_schema`]: crate::match_json_schema
+#[derive(Debug)]
+pub struct JsonSchemaMatcher<Schema> {
+    schema: Schema,
+    violations: Vec<SchemaViolation>,
+}
+
+impl<Schema> JsonSchemaMatcher<Schema> {
+    /// Create a new [`JsonSchemaMatcher`] from the expected JSON schema.
+    ///
+    /// The schema is always interpreted as draft 2020-12. If the schema is invalid, the matcher
+    /// will return an error.
+    pub fn new(schema: Schema) -> Self {
+        Self {
+            schema,
+            violations: Vec::new(),
+        }
+    }
+}
+
+impl<Schema, Actual> Match<Actual> for JsonSchemaMatcher<Schema>
+where
+    Schema: AsJson,
+    Actual: AsJson,
+{
+    type Fail = Vec<SchemaViolation>;
+
+    fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
+        let schema = self.schema.as_json()?;
+
+        let compiled = JSONSchema::options()
+            .with_draft(Draft::Draft202012)
+            .compile(&schema)
+            .map_err(|error| {
+                crate::Error::msg(format!("invalid JSON schema: {}", error))
+                    .with_kind(crate::ErrorKind::Input)
+            })?;
+
+        let instance = actual.as_json()?;
+
+        if let Err(errors) = compiled.validate(&instance) {
+            self.violations = errors.map(SchemaViolation::from).collect();
+        }
+
+        Ok(self.violations.is_empty())
+    }
+
+    fn fail(self, _: Actual) -> Self::Fail {
+        self.violations
+    }
+}
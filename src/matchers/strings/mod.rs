@@ -2,9 +2,15 @@
 mod casefold;
 #[cfg(feature = "json")]
 mod json;
+#[cfg(feature = "json-schema")]
+mod json_schema;
 #[cfg(feature = "regex")]
 mod regex;
 mod substr;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "yaml")]
+mod yaml;
 
 #[cfg(feature = "regex")]
 pub use self::regex::RegexMatcher;
@@ -12,4 +18,10 @@ pub use self::regex::RegexMatcher;
 pub use casefold::EqCasefoldMatcher;
 #[cfg(feature = "json")]
 pub use json::JsonMatcher;
+#[cfg(feature = "json-schema")]
+pub use json_schema::{AsJson, JsonSchemaMatcher, SchemaViolation};
 pub use substr::{ContainSubstrMatcher, HavePrefixMatcher, HaveSuffixMatcher};
+#[cfg(feature = "toml")]
+pub use toml::TomlMatcher;
+#[cfg(feature = "yaml")]
+pub use yaml::YamlMatcher;
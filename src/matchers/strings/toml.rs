@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+use toml::{from_str as toml_from_str, Value as TomlValue};
+
+use crate::core::Match;
+use crate::matchers::diff::{Diff, Diffable};
+
+/// The matcher for [`match_toml`].
+///
+/// [`match_toml`]: crate::match_toml
+#[derive(Debug)]
+pub struct TomlMatcher<'a> {
+    expected_toml: Cow<'a, str>,
+    parsed: Option<(TomlValue, TomlValue)>,
+}
+
+impl<'a> TomlMatcher<'a> {
+    /// Create a new [`TomlMatcher`] from the expected TOML string.
+    pub fn new(toml: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            expected_toml: toml.into(),
+            parsed: None,
+        }
+    }
+}
+
+impl<'a, Actual> Match<Actual> for TomlMatcher<'a>
+where
+    Actual: AsRef<str>,
+{
+    type Fail = Diff;
+
+    fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
+        let expected_value: TomlValue = toml_from_str(&self.expected_toml)?;
+        let actual_value: TomlValue = toml_from_str(actual.as_ref())?;
+        let is_match = actual_value == expected_value;
+        self.parsed = Some((expected_value, actual_value));
+        Ok(is_match)
+    }
+
+    fn fail(self, _: Actual) -> Self::Fail {
+        // If either string is invalid TOML, `matches` will return an error and this method will
+        // never be called. If something else is happening, it's a bug.
+        let (expected_value, actual_value) = self
+            .parsed
+            .expect("The TOML was never parsed, but the matcher failed. This shouldn't happen.");
+
+        expected_value.diff(actual_value)
+    }
+}
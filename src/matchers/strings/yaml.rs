@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+use serde_yaml::{from_str as yaml_from_str, Value as YamlValue};
+
+use crate::core::Match;
+use crate::matchers::diff::{Diff, Diffable};
+
+/// The matcher for [`match_yaml`].
+///
+/// [`match_yaml`]: crate::match_yaml
+#[derive(Debug)]
+pub struct YamlMatcher<'a> {
+    expected_yaml: Cow<'a, str>,
+    parsed: Option<(YamlValue, YamlValue)>,
+}
+
+impl<'a> YamlMatcher<'a> {
+    /// Create a new [`YamlMatcher`] from the expected YAML string.
+    pub fn new(yaml: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            expected_yaml: yaml.into(),
+            parsed: None,
+        }
+    }
+}
+
+impl<'a, Actual> Match<Actual> for YamlMatcher<'a>
+where
+    Actual: AsRef<str>,
+{
+    type Fail = Diff;
+
+    fn matches(&mut self, actual: &Actual) -> crate::Result<bool> {
+        let expected_value: YamlValue = yaml_from_str(&self.expected_yaml)?;
+        let actual_value: YamlValue = yaml_from_str(actual.as_ref())?;
+        let is_match = actual_value == expected_value;
+        self.parsed = Some((expected_value, actual_value));
+        Ok(is_match)
+    }
+
+    fn fail(self, _: Actual) -> Self::Fail {
+        // If either string is invalid YAML, `matches` will return an error and this method will
+        // never be called. If something else is happening, it's a bug.
+        let (expected_value, actual_value) = self
+            .parsed
+            .expect("The YAML was never parsed, but the matcher failed. This shouldn't happen.");
+
+        expected_value.diff(actual_value)
+    }
+}
@@ -1,14 +1,30 @@
-use crate::core::FormattedFailure;
+use crate::core::{FormattedFailure, MatchOutcome};
 
 /// A value that is returned by matchers that compose a list of other matchers.
 ///
 /// This represents the formatted failure output of each element in the list.
 ///
-/// This type is used by matchers like [`any`] and [`each`] that compose other matchers.
+/// This type is used by matchers like [`every`] and [`match_elements`] that test each element of a
+/// collection.
 ///
+/// [`every`]: crate::every
+/// [`match_elements`]: crate::match_elements
+pub type SomeFailures = Vec<Option<FormattedFailure>>;
+
+/// A value that is returned by matchers that compose a list of other matchers.
+///
+/// This is like [`SomeFailures`], except each matcher which succeeded can also have a description
+/// of what it tested. Descriptions are only recorded in [verbose mode]; see
+/// [`DynTransformMatch::describe_pos`].
+///
+/// This type is used by the [`any`], [`each`], and [`all`] matchers.
+///
+/// [verbose mode]: crate::Config::verbose
+/// [`DynTransformMatch::describe_pos`]: crate::core::DynTransformMatch::describe_pos
 /// [`any`]: crate::any
 /// [`each`]: crate::each
-pub type SomeFailures = Vec<Option<FormattedFailure>>;
+/// [`all`]: crate::all
+pub type SomeOutcomes = Vec<MatchOutcome<Option<FormattedFailure>, FormattedFailure>>;
 
 /// A value that is returned by matchers when the expected and actual values differ.
 ///
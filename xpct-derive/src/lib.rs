@@ -0,0 +1,242 @@
+/*!
+Derive macros for [xpct](https://docs.rs/xpct).
+
+You shouldn't depend on this crate directly. Instead, enable the `derive` Cargo feature of xpct and
+use the macros it re-exports.
+*/
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields, Generics, Ident, Type,
+};
+
+/// Derive `xpct::matchers::diff::Diffable` for a struct or enum.
+///
+/// See the docs for `Diffable` in xpct.
+#[proc_macro_derive(Diffable, attributes(diffable))]
+pub fn derive_diffable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A field of a struct or enum variant.
+struct Field {
+    /// The name of the field in the diff.
+    name: String,
+
+    /// The identifier to bind the field to in `self`.
+    this: Ident,
+
+    /// The identifier to bind the field to in `other`.
+    other: Ident,
+
+    /// The member used to access the field, for destructuring.
+    member: syn::Member,
+
+    ty: Type,
+
+    /// Whether the field is marked `#[diffable(nested)]`.
+    nested: bool,
+}
+
+fn is_nested(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut nested = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("diffable")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nested") {
+                nested = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported diffable attribute, expected `nested`"))
+            }
+        })?;
+    }
+
+    Ok(nested)
+}
+
+fn collect_fields(fields: &Fields) -> syn::Result<Vec<Field>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (name, member) = match &field.ident {
+                Some(ident) => (ident.to_string(), syn::Member::Named(ident.clone())),
+                None => (index.to_string(), syn::Member::Unnamed(index.into())),
+            };
+
+            Ok(Field {
+                name,
+                this: format_ident!("this_{}", index),
+                other: format_ident!("other_{}", index),
+                member,
+                ty: field.ty.clone(),
+                nested: is_nested(&field.attrs)?,
+            })
+        })
+        .collect()
+}
+
+/// Generate the statements that diff each field and push the segments onto `segments`.
+fn diff_fields(fields: &[Field]) -> TokenStream2 {
+    let statements = fields.iter().map(|field| {
+        let Field {
+            name, this, other, ..
+        } = field;
+
+        if field.nested {
+            quote! {
+                segments.extend(::xpct::matchers::diff::nest_diff(#name, #this, #other));
+            }
+        } else {
+            quote! {
+                segments.extend(::xpct::matchers::diff::diff_field(#name, #this, &#other));
+            }
+        }
+    });
+
+    quote! { #(#statements)* }
+}
+
+/// Generate a pattern which destructures the fields of a struct or enum variant.
+fn pattern(path: TokenStream2, fields: &[Field], other: bool) -> TokenStream2 {
+    let bindings = fields.iter().map(|field| {
+        let member = &field.member;
+        let ident = if other { &field.other } else { &field.this };
+        quote! { #member: #ident }
+    });
+
+    quote! { #path { #(#bindings),* } }
+}
+
+/// Add the bounds each field type needs to be diffed.
+fn add_bounds(generics: &mut Generics, fields: &[Field]) {
+    let where_clause = generics.make_where_clause();
+
+    for field in fields {
+        let ty = &field.ty;
+
+        if field.nested {
+            where_clause.predicates.push(parse_quote! {
+                #ty: ::xpct::matchers::diff::Diffable<#ty>
+                    + ::core::cmp::PartialEq
+                    + ::core::fmt::Debug
+            });
+        } else {
+            where_clause
+                .predicates
+                .push(parse_quote! { #ty: ::core::cmp::PartialEq + ::core::fmt::Debug });
+        }
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = collect_fields(&data.fields)?;
+            add_bounds(&mut generics, &fields);
+
+            let this_pattern = pattern(quote! { Self }, &fields, false);
+            let other_pattern = pattern(quote! { Self }, &fields, true);
+            let diff_fields = diff_fields(&fields);
+
+            quote! {
+                let #this_pattern = self;
+                let #other_pattern = other;
+                let mut segments = ::std::vec::Vec::new();
+                #diff_fields
+                segments
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+
+            for variant in &data.variants {
+                let variant_name = &variant.ident;
+                let fields = collect_fields(&variant.fields)?;
+                add_bounds(&mut generics, &fields);
+
+                let this_pattern = pattern(quote! { Self::#variant_name }, &fields, false);
+                let other_pattern = pattern(quote! { Self::#variant_name }, &fields, true);
+                let diff_fields = diff_fields(&fields);
+
+                // Variants without fields have nothing to compare, so show the whole value.
+                let arm_body = if fields.is_empty() {
+                    quote! {
+                        ::std::vec![::xpct::matchers::diff::DiffSegment::from_debug(
+                            self,
+                            ::xpct::matchers::diff::DiffTag::Equal,
+                        )]
+                    }
+                } else {
+                    quote! {
+                        let mut segments = ::std::vec::Vec::new();
+                        #diff_fields
+                        segments
+                    }
+                };
+
+                arms.push(quote! {
+                    (#this_pattern, #other_pattern) => {
+                        #arm_body
+                    }
+                });
+            }
+
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { Self: ::core::fmt::Debug });
+
+            // If the variants are different, there are no fields to compare.
+            quote! {
+                match (self, other) {
+                    #(#arms)*
+                    #[allow(unreachable_patterns)]
+                    (this, other) => ::std::vec![
+                        ::xpct::matchers::diff::DiffSegment::from_debug(
+                            this,
+                            ::xpct::matchers::diff::DiffTag::Delete,
+                        ),
+                        ::xpct::matchers::diff::DiffSegment::from_debug(
+                            other,
+                            ::xpct::matchers::diff::DiffTag::Insert,
+                        ),
+                    ],
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`Diffable` can't be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::xpct::matchers::diff::Diffable<#name #ty_generics>
+            for #name #ty_generics #where_clause
+        {
+            const KIND: ::xpct::matchers::diff::DiffKind = ::xpct::matchers::diff::DiffKind::Map;
+
+            fn diff(&self, other: #name #ty_generics) -> ::xpct::matchers::diff::Diff {
+                #body
+            }
+        }
+    })
+}